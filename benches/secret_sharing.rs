@@ -0,0 +1,114 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_bigint::BigInt;
+use secret_sharing::algorithms::feldman_vss::FeldmanVSS;
+use secret_sharing::algorithms::primes::{p256, p32};
+use secret_sharing::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+
+const THRESHOLDS: [usize; 3] = [2, 10, 50];
+
+// (label, prime) pairs every benchmark runs over
+fn primes() -> [(&'static str, BigInt); 2] {
+    [("p32", p32()), ("p256", p256())]
+}
+
+fn bench_shamir(c: &mut Criterion) {
+    let mut split = c.benchmark_group("generate_shares");
+    for (label, prime) in primes() {
+        for threshold in THRESHOLDS {
+            let mut shamir =
+                ShamirSecretSharing::new(threshold, 2 * threshold, Some(prime.clone())).unwrap();
+            split.bench_function(BenchmarkId::new(label, threshold), |b| {
+                b.iter(|| shamir.generate_shares(BigInt::from(1234)).unwrap())
+            });
+        }
+    }
+    split.finish();
+
+    let mut combine = c.benchmark_group("reconstruct");
+    for (label, prime) in primes() {
+        for threshold in THRESHOLDS {
+            let mut shamir =
+                ShamirSecretSharing::new(threshold, 2 * threshold, Some(prime.clone())).unwrap();
+            let shares = shamir.generate_shares(BigInt::from(1234)).unwrap();
+            combine.bench_function(BenchmarkId::new(label, threshold), |b| {
+                b.iter(|| shamir.reconstruct(&shares[..threshold]).unwrap())
+            });
+        }
+    }
+    combine.finish();
+}
+
+fn bench_feldman(c: &mut Criterion) {
+    // dealing is share generation plus one modpow per coefficient for the commitments
+    let mut commit = c.benchmark_group("generate_committments");
+    for (label, prime) in primes() {
+        for threshold in THRESHOLDS {
+            let mut vss = FeldmanVSS::new(threshold, 2 * threshold, Some(prime.clone())).unwrap();
+            commit.bench_function(BenchmarkId::new(label, threshold), |b| {
+                b.iter(|| vss.generate_shares(BigInt::from(1234)).unwrap())
+            });
+        }
+    }
+    commit.finish();
+
+    let mut validate = c.benchmark_group("validate_shares");
+    for (label, prime) in primes() {
+        for threshold in THRESHOLDS {
+            let mut vss = FeldmanVSS::new(threshold, 2 * threshold, Some(prime.clone())).unwrap();
+            let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+            let share = response.shares.last().unwrap().clone();
+            validate.bench_function(BenchmarkId::new(label, threshold), |b| {
+                b.iter(|| assert!(vss.validate_shares(share.clone())))
+            });
+        }
+    }
+    validate.finish();
+}
+
+fn bench_shamir_internals(c: &mut Criterion) {
+    // repeated dealings with and without the i^j mod prime cache
+    let mut powers = c.benchmark_group("precomputed_index_powers");
+    let mut shamir = ShamirSecretSharing::new(50, 200, Some(p32())).unwrap();
+    powers.bench_function("uncached", |b| {
+        b.iter(|| shamir.generate_shares(BigInt::from(1234)).unwrap())
+    });
+    shamir.precompute_index_powers();
+    powers.bench_function("cached", |b| {
+        b.iter(|| shamir.generate_shares(BigInt::from(1234)).unwrap())
+    });
+    powers.finish();
+
+    // lagrange_interpolation takes owned coordinates, reconstruct borrows the shares
+    let mut interpolate = c.benchmark_group("interpolation_ownership");
+    let mut shamir = ShamirSecretSharing::new(100, 100, Some(p256())).unwrap();
+    let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+    interpolate.bench_function("cloned", |b| {
+        b.iter(|| {
+            let (xs, ys) = shares.iter().cloned().unzip();
+            shamir.lagrange_interpolation(xs, ys).unwrap()
+        })
+    });
+    interpolate.bench_function("borrowed", |b| {
+        b.iter(|| shamir.reconstruct_tuples(&shares).unwrap())
+    });
+    interpolate.finish();
+
+    // generate_shares evaluates in parallel, against a plain sequential horner loop
+    let mut evaluate = c.benchmark_group("share_evaluation");
+    let mut shamir = ShamirSecretSharing::new(100, 1000, Some(p32())).unwrap();
+    shamir.generate_shares(BigInt::from(1234)).unwrap();
+    evaluate.bench_function("parallel", |b| {
+        b.iter(|| shamir.generate_shares(BigInt::from(1234)).unwrap())
+    });
+    evaluate.bench_function("sequential", |b| {
+        b.iter(|| {
+            (1..=1000)
+                .map(|i| shamir.polynomial.evaluate(i))
+                .collect::<Vec<BigInt>>()
+        })
+    });
+    evaluate.finish();
+}
+
+criterion_group!(benches, bench_shamir, bench_feldman, bench_shamir_internals);
+criterion_main!(benches);
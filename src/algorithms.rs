@@ -1,2 +1,30 @@
+pub mod berlekamp_welch;
+pub mod complaint_round;
+pub mod distribution;
+pub mod dkg;
+pub mod encoding;
+pub mod feldman_aggregator;
 pub mod feldman_vss;
+pub mod field;
+#[cfg(feature = "std")]
+pub mod field_migration;
+pub mod gf256;
+pub mod gf2n;
+pub mod key_rotation;
+pub mod mnemonic;
+pub mod packed;
+pub mod pedersen_vss;
+pub mod polynomial;
+pub mod primality;
+pub mod primes;
+pub mod reconstruction_trace;
+#[cfg(feature = "std")]
+pub mod scheme;
 pub mod shamir_secret_sharing;
+pub mod share;
+pub mod share_bundle;
+pub mod signed_share;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod weighted;
@@ -0,0 +1,236 @@
+use alloc::{vec, vec::Vec};
+
+use num_bigint::BigInt;
+
+use super::shamir_secret_sharing::{check_congruent_indices, mod_inverse, ShamirSecretSharing};
+use crate::error::SecretSharingError;
+
+impl ShamirSecretSharing {
+    // berlekamp-welch decoding: recovers the secret from at least threshold + 2 * max_errors
+    // shares even when up to max_errors of them are corrupted
+    pub fn reconstruct_with_errors(
+        &self,
+        shares: &[(usize, BigInt)],
+        max_errors: usize,
+    ) -> Result<BigInt, SecretSharingError> {
+        let k = self.threshold;
+        let need = k + 2 * max_errors;
+        if shares.len() < need {
+            return Err(SecretSharingError::InsufficientShares {
+                got: shares.len(),
+                need,
+            });
+        }
+        for (n, (index, _)) in shares.iter().enumerate() {
+            if shares[..n].iter().any(|(other, _)| other == index) {
+                return Err(SecretSharingError::DuplicateShareIndex { index: *index });
+            }
+        }
+        check_congruent_indices(shares, &self.prime)?;
+
+        let prime = &self.prime;
+        let e = max_errors;
+        // unknowns: q0..q(k+e-1) of Q(x) then e0..e(e-1) of the monic error locator E(x)
+        // equations: Q(xi) - yi * (e0 + ... + e(e-1) xi^(e-1)) = yi * xi^e
+        let mut rows = Vec::with_capacity(shares.len());
+        for (x, y) in shares.iter() {
+            let x = BigInt::from(*x);
+            let y = ((y % prime) + prime) % prime;
+            let powers: Vec<BigInt> = (0..k + e)
+                .scan(BigInt::from(1), |acc, _| {
+                    let current = acc.clone();
+                    *acc = (&*acc * &x) % prime;
+                    Some(current)
+                })
+                .collect();
+            let mut row: Vec<BigInt> = powers.clone();
+            row.extend(powers[..e].iter().map(|p| (-(&y * p)) % prime));
+            let x_to_e = x.modpow(&BigInt::from(e), prime);
+            row.push((&y * x_to_e) % prime);
+            rows.push(row);
+        }
+        let solution =
+            solve_mod(rows, k + 2 * e, prime).ok_or(SecretSharingError::DecodingFailed)?;
+
+        let q = solution[..k + e].to_vec();
+        let mut error_locator = solution[k + e..].to_vec();
+        error_locator.push(BigInt::from(1));
+        let (p, remainder) = divide_monic(&q, &error_locator, prime);
+        if remainder.iter().any(|r| *r != BigInt::from(0)) || p.len() > k {
+            return Err(SecretSharingError::DecodingFailed);
+        }
+        Ok(p.first().cloned().unwrap_or_else(|| BigInt::from(0)))
+    }
+
+    // deal with enough redundancy to survive corruption_tolerance malicious shares: the
+    // instance gets total_shares = threshold + 2 * corruption_tolerance
+    #[cfg(feature = "std")]
+    pub fn deal_robust(
+        threshold: usize,
+        secret: BigInt,
+        corruption_tolerance: usize,
+    ) -> Result<(Self, Vec<(usize, BigInt)>), SecretSharingError> {
+        let total_shares = threshold + 2 * corruption_tolerance;
+        let mut shamir = Self::for_secret(threshold, total_shares, &secret)?;
+        let shares = shamir.generate_shares_tuples(secret)?;
+        Ok((shamir, shares))
+    }
+
+    // the tolerance an instance was dealt with by deal_robust, read back from its share counts
+    pub fn corruption_tolerance(&self) -> usize {
+        (self.total_shares - self.threshold) / 2
+    }
+
+    // decode shares from deal_robust, correcting up to corruption_tolerance bad shares
+    pub fn reconstruct_robust(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<BigInt, SecretSharingError> {
+        self.reconstruct_with_errors(shares, self.corruption_tolerance())
+    }
+}
+
+// gaussian elimination mod prime on rows of [coefficients | rhs], free variables set to zero
+fn solve_mod(mut rows: Vec<Vec<BigInt>>, unknowns: usize, prime: &BigInt) -> Option<Vec<BigInt>> {
+    let normalize = |v: &BigInt| ((v % prime) + prime) % prime;
+    let mut pivots = Vec::new();
+    let mut rank = 0;
+    for col in 0..unknowns {
+        let Some(pivot) = (rank..rows.len()).find(|r| normalize(&rows[*r][col]) != BigInt::from(0))
+        else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inverse = mod_inverse(&rows[rank][col], prime)?;
+        rows[rank] = rows[rank]
+            .iter()
+            .map(|v| normalize(&(v * &inverse)))
+            .collect();
+        for r in 0..rows.len() {
+            if r != rank {
+                let factor = normalize(&rows[r][col]);
+                if factor != BigInt::from(0) {
+                    let pivot_row = rows[rank].clone();
+                    for (v, pv) in rows[r].iter_mut().zip(pivot_row.iter()) {
+                        *v = normalize(&(&*v - &factor * pv));
+                    }
+                }
+            }
+        }
+        pivots.push(col);
+        rank += 1;
+    }
+    // a non zero rhs in an all zero row means the system is inconsistent
+    if rows[rank..]
+        .iter()
+        .any(|row| normalize(&row[unknowns]) != BigInt::from(0))
+    {
+        return None;
+    }
+    let mut solution = vec![BigInt::from(0); unknowns];
+    for (r, col) in pivots.into_iter().enumerate() {
+        solution[col] = normalize(&rows[r][unknowns]);
+    }
+    Some(solution)
+}
+
+// long division of a by the monic polynomial b (coefficients lowest degree first) mod prime
+fn divide_monic(a: &[BigInt], b: &[BigInt], prime: &BigInt) -> (Vec<BigInt>, Vec<BigInt>) {
+    let mut remainder = a.to_vec();
+    if a.len() < b.len() {
+        return (Vec::new(), remainder);
+    }
+    let mut quotient = vec![BigInt::from(0); a.len() - b.len() + 1];
+    for i in (0..quotient.len()).rev() {
+        let factor = ((&remainder[i + b.len() - 1] % prime) + prime) % prime;
+        for (j, bj) in b.iter().enumerate() {
+            remainder[i + j] = (&remainder[i + j] - &factor * bj) % prime;
+        }
+        quotient[i] = factor;
+    }
+    let remainder = remainder
+        .into_iter()
+        .map(|r| ((r % prime) + prime) % prime)
+        .collect();
+    (quotient, remainder)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn corrupted_share_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 7, None).unwrap();
+        let mut shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        shares[4].1 += 987654;
+
+        assert_ne!(
+            shamir.reconstruct_tuples(&shares[2..5]).unwrap(),
+            BigInt::from(1234),
+            "Plain interpolation should be fooled by the corrupted share"
+        );
+        assert_eq!(
+            shamir.reconstruct_with_errors(&shares, 1).unwrap(),
+            BigInt::from(1234),
+            "Decoding should correct the corrupted share"
+        );
+    }
+
+    #[test]
+    fn two_corrupted_shares_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 7, None).unwrap();
+        let mut shares = shamir.generate_shares_tuples(BigInt::from(4321)).unwrap();
+        shares[0].1 += 1;
+        shares[6].1 += 2;
+        assert_eq!(
+            shamir.reconstruct_with_errors(&shares, 2).unwrap(),
+            BigInt::from(4321),
+            "Decoding should correct two corrupted shares out of seven"
+        );
+    }
+
+    #[test]
+    fn no_errors_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 7, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            shamir.reconstruct_with_errors(&shares, 2).unwrap(),
+            BigInt::from(1234),
+            "Clean shares should decode with room for errors to spare"
+        );
+    }
+
+    #[test]
+    fn insufficient_shares_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 7, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            shamir.reconstruct_with_errors(&shares[..4], 1),
+            Err(SecretSharingError::InsufficientShares { got: 4, need: 5 }),
+            "Correcting one error needs threshold + 2 shares"
+        );
+    }
+
+    #[test]
+    fn deal_robust_test() {
+        let (shamir, mut shares) =
+            ShamirSecretSharing::deal_robust(3, BigInt::from(2024), 1).unwrap();
+        assert_eq!(shares.len(), 5, "Tolerating one error needs threshold + 2");
+        assert_eq!(shamir.corruption_tolerance(), 1);
+
+        // one participant lies about their share
+        shares[1].1 += 31337;
+        assert_eq!(
+            shamir.reconstruct_robust(&shares).unwrap(),
+            BigInt::from(2024),
+            "The malicious share should be corrected"
+        );
+        assert_eq!(
+            shamir.reconstruct_robust(&shares[..4]),
+            Err(SecretSharingError::InsufficientShares { got: 4, need: 5 })
+        );
+    }
+}
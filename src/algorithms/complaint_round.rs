@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
+
+use super::feldman_vss::{verify_share, FeldmanResponse, FeldmanVSS};
+use crate::error::SecretSharingError;
+
+// complaint phase of a feldman dealing: participants whose share fails verification complain
+// publicly, the dealer has to reveal those shares, and a revealed share that still fails the
+// commitments disqualifies the dealer
+pub struct ComplaintRound<'a> {
+    vss: &'a FeldmanVSS,
+    dealing: &'a FeldmanResponse,
+    complaints: Vec<usize>,
+    disqualified: bool,
+}
+
+impl<'a> ComplaintRound<'a> {
+    pub fn new(vss: &'a FeldmanVSS, dealing: &'a FeldmanResponse) -> Self {
+        Self {
+            vss,
+            dealing,
+            complaints: Vec::new(),
+            disqualified: false,
+        }
+    }
+
+    // participant index broadcasts a complaint against its share, repeated complaints count once
+    pub fn raise_complaint(&mut self, index: usize) -> Result<(), SecretSharingError> {
+        if !self.dealing.shares.iter().any(|(i, _)| *i == index) {
+            return Err(SecretSharingError::InvalidShareIndex { index });
+        }
+        if !self.complaints.contains(&index) {
+            self.complaints.push(index);
+        }
+        Ok(())
+    }
+
+    // the dealer publicly reveals the share it dealt to a complaining participant
+    pub fn dealer_respond(&self, index: usize) -> Result<(usize, BigInt), SecretSharingError> {
+        if !self.complaints.contains(&index) {
+            return Err(SecretSharingError::InvalidShareIndex { index });
+        }
+        self.dealing
+            .shares
+            .iter()
+            .find(|(i, _)| *i == index)
+            .cloned()
+            .ok_or(SecretSharingError::InvalidShareIndex { index })
+    }
+
+    // everyone checks the revealed share against the commitments. a valid reveal settles the
+    // complaint, an invalid one (or a reveal nobody complained about) disqualifies the dealer
+    pub fn resolve(&mut self, revealed_share: (usize, BigInt)) -> bool {
+        let Some(position) = self.complaints.iter().position(|i| *i == revealed_share.0) else {
+            self.disqualified = true;
+            return false;
+        };
+        let valid = verify_share(self.vss.group(), &self.dealing.committments, revealed_share);
+        if valid {
+            self.complaints.remove(position);
+        } else {
+            self.disqualified = true;
+        }
+        valid
+    }
+
+    pub fn pending_complaints(&self) -> &[usize] {
+        &self.complaints
+    }
+
+    pub fn is_disqualified(&self) -> bool {
+        self.disqualified
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::complaint_round::ComplaintRound;
+    use crate::algorithms::feldman_vss::FeldmanVSS;
+    use crate::algorithms::shamir_secret_sharing::default_prime;
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    fn create_feldman_vss() -> FeldmanVSS {
+        FeldmanVSS::new(3, 5, Some(default_prime())).unwrap()
+    }
+
+    #[test]
+    fn valid_complaint_resolution_test() {
+        let mut vss = create_feldman_vss();
+        let dealing = vss.generate_shares(BigInt::from(1234)).unwrap();
+        let mut round = ComplaintRound::new(&vss, &dealing);
+
+        // participant 2 claims its share did not verify, e.g. it was garbled in transit
+        round.raise_complaint(2).unwrap();
+        assert_eq!(round.pending_complaints(), &[2]);
+        let revealed = round.dealer_respond(2).unwrap();
+        assert!(round.resolve(revealed), "Honest reveal should verify");
+        assert!(round.pending_complaints().is_empty());
+        assert!(!round.is_disqualified(), "Honest dealer stays qualified");
+    }
+
+    #[test]
+    fn dealer_disqualification_test() {
+        let mut vss = create_feldman_vss();
+        let mut dealing = vss.generate_shares(BigInt::from(1234)).unwrap();
+        // dealer hands participant 3 a share inconsistent with its commitments
+        dealing.shares[2].1 += 1;
+        let mut round = ComplaintRound::new(&vss, &dealing);
+
+        round.raise_complaint(3).unwrap();
+        let revealed = round.dealer_respond(3).unwrap();
+        assert!(!round.resolve(revealed), "Bad reveal should not verify");
+        assert!(round.is_disqualified(), "Dealer should be disqualified");
+        assert_eq!(
+            round.raise_complaint(9),
+            Err(SecretSharingError::InvalidShareIndex { index: 9 })
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use alloc::{string::String, vec::Vec};
+
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+// transport encryption for a single recipient, bound to that recipient's key
+pub trait Encryptor {
+    fn encrypt(&self, plaintext: &[u8]) -> Vec<u8>;
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, SecretSharingError>;
+}
+
+pub struct Recipient<'a> {
+    // which share (x coordinate) this recipient receives
+    pub index: usize,
+    pub label: Option<String>,
+    pub encryptor: Option<&'a dyn Encryptor>,
+}
+
+// payload of a blob before optional encryption
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipientShare {
+    pub index: usize,
+    pub label: Option<String>,
+    pub values: Vec<BigInt>,
+}
+
+impl RecipientShare {
+    // decrypt (if needed) and parse a blob produced by deal_to_recipients
+    pub fn open(
+        blob: &[u8],
+        decryptor: Option<&dyn Encryptor>,
+    ) -> Result<Self, SecretSharingError> {
+        let plaintext = match decryptor {
+            Some(decryptor) => decryptor.decrypt(blob)?,
+            None => blob.to_vec(),
+        };
+        serde_json::from_slice(&plaintext)
+            .map_err(|_| SecretSharingError::InvalidEncoding("malformed recipient share"))
+    }
+}
+
+impl ShamirSecretSharing {
+    // split a byte secret and produce one ready to send blob per recipient, in recipient order
+    #[cfg(feature = "std")]
+    pub fn deal_to_recipients(
+        &mut self,
+        secret: &[u8],
+        recipients: &[Recipient],
+    ) -> Result<Vec<Vec<u8>>, SecretSharingError> {
+        for (n, recipient) in recipients.iter().enumerate() {
+            let out_of_range = recipient.index == 0 || recipient.index > self.total_shares;
+            let duplicate = recipients[..n].iter().any(|r| r.index == recipient.index);
+            if out_of_range || duplicate {
+                return Err(SecretSharingError::InvalidShareIndex {
+                    index: recipient.index,
+                });
+            }
+        }
+
+        let shares = self.split_bytes(secret)?;
+        let mut blobs = Vec::with_capacity(recipients.len());
+        for recipient in recipients {
+            // split_bytes hands out x coordinates 1..=n in order
+            let payload = RecipientShare {
+                index: recipient.index,
+                label: recipient.label.clone(),
+                values: shares[recipient.index - 1].1.clone(),
+            };
+            let plaintext = serde_json::to_vec(&payload)
+                .map_err(|_| SecretSharingError::InvalidEncoding("unserializable share"))?;
+            let blob = match recipient.encryptor {
+                Some(encryptor) => encryptor.encrypt(&plaintext),
+                None => plaintext,
+            };
+            blobs.push(blob);
+        }
+        Ok(blobs)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::distribution::{Encryptor, Recipient, RecipientShare};
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::error::SecretSharingError;
+
+    // xor with a repeating key, only good enough for tests
+    struct XorEncryptor(Vec<u8>);
+
+    impl Encryptor for XorEncryptor {
+        fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+            plaintext
+                .iter()
+                .zip(self.0.iter().cycle())
+                .map(|(b, k)| b ^ k)
+                .collect()
+        }
+
+        fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, SecretSharingError> {
+            Ok(self.encrypt(ciphertext))
+        }
+    }
+
+    #[test]
+    fn deal_to_recipients_test() {
+        let secret = b"correct horse battery staple".to_vec();
+        let encryptor = XorEncryptor(vec![0x5a, 0x13, 0xc7]);
+        let recipients = vec![
+            Recipient {
+                index: 1,
+                label: Some("alice".to_string()),
+                encryptor: Some(&encryptor),
+            },
+            Recipient {
+                index: 2,
+                label: Some("bob".to_string()),
+                encryptor: None,
+            },
+            Recipient {
+                index: 3,
+                label: None,
+                encryptor: None,
+            },
+        ];
+
+        let mut shamir = ShamirSecretSharing::new(3, 3, None).unwrap();
+        let blobs = shamir.deal_to_recipients(&secret, &recipients).unwrap();
+        assert_eq!(blobs.len(), 3, "Every recipient should get a blob");
+        assert!(
+            RecipientShare::open(&blobs[0], None).is_err(),
+            "Encrypted blob should not parse without decrypting"
+        );
+
+        let alice = RecipientShare::open(&blobs[0], Some(&encryptor)).unwrap();
+        let bob = RecipientShare::open(&blobs[1], None).unwrap();
+        let carol = RecipientShare::open(&blobs[2], None).unwrap();
+        assert_eq!(alice.label.as_deref(), Some("alice"));
+        assert_eq!(bob.index, 2);
+
+        let shares: Vec<_> = [alice, bob, carol]
+            .into_iter()
+            .map(|share| (share.index, share.values))
+            .collect();
+        assert_eq!(
+            shamir.combine_bytes(&shares).unwrap(),
+            secret,
+            "Recovered bytes should match the secret"
+        );
+    }
+
+    #[test]
+    fn deal_to_recipients_rejects_bad_index() {
+        let mut shamir = ShamirSecretSharing::new(2, 3, None).unwrap();
+        let recipients = vec![
+            Recipient {
+                index: 1,
+                label: None,
+                encryptor: None,
+            },
+            Recipient {
+                index: 1,
+                label: None,
+                encryptor: None,
+            },
+        ];
+        assert_eq!(
+            shamir.deal_to_recipients(b"secret", &recipients),
+            Err(SecretSharingError::InvalidShareIndex { index: 1 }),
+            "Duplicate recipient index should be rejected"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use alloc::{vec, vec::Vec};
+
+use num_bigint::BigInt;
+
+use super::feldman_aggregator::FeldmanAggregator;
+use super::feldman_vss::{CommitmentGroup, FeldmanResponse};
+use crate::error::SecretSharingError;
+
+// dealer-less key generation: every party deals a random secret with FeldmanVSS::generate_shares
+// and broadcasts the commitments. party i receives all_shares_for_me[k] from dealer k, checks it
+// against all_commitments[k] and sums them. the joint secret is the sum of the dealt secrets and
+// nobody ever holds it. returns the aggregate share and the aggregate commitments every party
+// can compute for themselves
+pub fn combine_dkg(
+    all_shares_for_me: &[(usize, BigInt)],
+    all_commitments: &[Vec<BigInt>],
+    group: &CommitmentGroup,
+) -> Result<((usize, BigInt), Vec<BigInt>), SecretSharingError> {
+    if all_shares_for_me.len() != all_commitments.len() {
+        return Err(SecretSharingError::IncompatibleDealing(
+            "one commitment vector per dealer is needed",
+        ));
+    }
+
+    // every dealer's contribution is a one share dealing, the aggregator sums them
+    let mut aggregator = FeldmanAggregator::new(group.clone());
+    for (dealer, (share, commitments)) in all_shares_for_me.iter().zip(all_commitments).enumerate()
+    {
+        let dealing = FeldmanResponse {
+            shares: vec![share.clone()],
+            committments: commitments.clone(),
+        };
+        // complaints against a dealer are raised here, before anything is summed
+        let valid = aggregator.validate_share(&dealing, share.clone());
+        aggregator.add_dealing(dealing)?;
+        if !valid {
+            return Err(SecretSharingError::DealerVerificationFailed { dealer: dealer + 1 });
+        }
+    }
+
+    let mut joint = aggregator.aggregate()?;
+    let share = joint.shares.remove(0);
+    Ok((share, joint.committments))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::dkg::combine_dkg;
+    use crate::algorithms::feldman_vss::{verify_share, FeldmanResponse, FeldmanVSS};
+    use crate::algorithms::shamir_secret_sharing::{default_prime, ShamirSecretSharing};
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    fn deal(secrets: &[i64]) -> (FeldmanVSS, Vec<FeldmanResponse>) {
+        let mut vss = FeldmanVSS::new(2, 3, Some(default_prime())).unwrap();
+        let dealings = secrets
+            .iter()
+            .map(|s| vss.generate_shares(BigInt::from(*s)).unwrap())
+            .collect();
+        (vss, dealings)
+    }
+
+    // what party `me` (0 based) receives from every dealer
+    fn inbox(dealings: &[FeldmanResponse], me: usize) -> (Vec<(usize, BigInt)>, Vec<Vec<BigInt>>) {
+        dealings
+            .iter()
+            .map(|d| (d.shares[me].clone(), d.committments.clone()))
+            .unzip()
+    }
+
+    #[test]
+    fn three_party_dkg_test() {
+        let (vss, dealings) = deal(&[100, 200, 300]);
+        let group = vss.group();
+
+        let mut aggregate = Vec::new();
+        for me in 0..3 {
+            let (shares, commitments) = inbox(&dealings, me);
+            let (share, joint) = combine_dkg(&shares, &commitments, group).unwrap();
+            assert!(
+                verify_share(group, &joint, share.clone()),
+                "Aggregate share should verify against aggregate commitments"
+            );
+            aggregate.push(share);
+        }
+
+        let shamir = ShamirSecretSharing::new(2, 3, Some(vss.prime().clone())).unwrap();
+        assert_eq!(
+            shamir.reconstruct_tuples(&aggregate[1..]).unwrap(),
+            BigInt::from(600),
+            "Joint secret should be the sum of every contribution"
+        );
+    }
+
+    #[test]
+    fn dkg_cheating_dealer_test() {
+        let (vss, dealings) = deal(&[1, 2, 3]);
+        let (mut shares, commitments) = inbox(&dealings, 0);
+        shares[1].1 += 1;
+        assert_eq!(
+            combine_dkg(&shares, &commitments, vss.group()),
+            Err(SecretSharingError::DealerVerificationFailed { dealer: 2 })
+        );
+    }
+
+    #[test]
+    fn dkg_mismatched_dealings_test() {
+        let (vss, dealings) = deal(&[1, 2, 3]);
+        let (mut shares, commitments) = inbox(&dealings, 0);
+        shares[2] = dealings[2].shares[1].clone();
+        assert_eq!(
+            combine_dkg(&shares, &commitments, vss.group()),
+            Err(SecretSharingError::IncompatibleDealing(
+                "share indices differ"
+            ))
+        );
+        assert_eq!(
+            combine_dkg(&[], &[], vss.group()),
+            Err(SecretSharingError::IncompatibleDealing("no dealings added"))
+        );
+    }
+}
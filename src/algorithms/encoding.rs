@@ -0,0 +1,251 @@
+use alloc::{format, string::String, vec, vec::Vec};
+
+use num_bigint::{BigInt, Sign};
+
+use crate::error::SecretSharingError;
+
+// share value as big-endian bytes left padded with zeros to exactly width bytes, e.g. for fixed
+// width database columns. shares are reduced mod prime, so the prime's byte length
+// (ShamirSecretSharing::share_byte_len) fits every share of a dealing
+pub fn share_to_fixed_bytes(
+    share: &(usize, BigInt),
+    width: usize,
+) -> Result<(usize, Vec<u8>), SecretSharingError> {
+    let (sign, value) = share.1.to_bytes_be();
+    if sign == Sign::Minus {
+        return Err(SecretSharingError::InvalidEncoding("negative share value"));
+    }
+    // to_bytes_be returns [0] for zero, which would otherwise take up a byte
+    let value: &[u8] = if sign == Sign::NoSign { &[] } else { &value };
+    if value.len() > width {
+        return Err(SecretSharingError::InvalidEncoding(
+            "share value does not fit in width",
+        ));
+    }
+    let mut bytes = vec![0; width - value.len()];
+    bytes.extend_from_slice(value);
+    Ok((share.0, bytes))
+}
+
+// inverse of share_to_fixed_bytes
+pub fn share_from_fixed_bytes(share: &(usize, Vec<u8>)) -> (usize, BigInt) {
+    (share.0, BigInt::from_bytes_be(Sign::Plus, &share.1))
+}
+
+// byte secret as a BigInt. a 0x01 marker byte goes in front so leading zero bytes of the
+// secret survive the conversion, the secret has to stay below the prime including the marker
+pub fn secret_from_bytes(bytes: &[u8]) -> BigInt {
+    let mut marked = Vec::with_capacity(bytes.len() + 1);
+    marked.push(0x01);
+    marked.extend_from_slice(bytes);
+    BigInt::from_bytes_be(Sign::Plus, &marked)
+}
+
+// inverse of secret_from_bytes. a value without the leading 0x01 marker did not come from
+// secret_from_bytes (or was reconstructed wrongly) and is an error rather than raw bytes
+pub fn bytes_from_secret(secret: &BigInt) -> Result<Vec<u8>, SecretSharingError> {
+    let (sign, bytes) = secret.to_bytes_be();
+    match bytes.split_first() {
+        Some((0x01, rest)) if sign == Sign::Plus => Ok(rest.to_vec()),
+        _ => Err(SecretSharingError::InvalidEncoding("missing secret marker")),
+    }
+}
+
+// non negative value from hex digits, an optional 0x prefix is accepted
+pub fn bigint_from_hex(hex: &str) -> Result<BigInt, SecretSharingError> {
+    let hex = hex.trim();
+    let digits = hex.strip_prefix("0x").unwrap_or(hex);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(SecretSharingError::InvalidEncoding("invalid hex value"));
+    }
+    BigInt::parse_bytes(digits.as_bytes(), 16)
+        .ok_or(SecretSharingError::InvalidEncoding("invalid hex value"))
+}
+
+// lowercase hex digits without prefix
+pub fn bigint_to_hex(value: &BigInt) -> String {
+    value.to_str_radix(16)
+}
+
+// share as "index:hexvalue"
+pub fn share_to_hex(share: &(usize, BigInt)) -> String {
+    format!("{}:{}", share.0, bigint_to_hex(&share.1))
+}
+
+// inverse of share_to_hex, the index is decimal
+pub fn share_from_hex(s: &str) -> Result<(usize, BigInt), SecretSharingError> {
+    let (index, value) = s
+        .split_once(':')
+        .ok_or(SecretSharingError::InvalidEncoding(
+            "share is not index:hexvalue",
+        ))?;
+    let index = index
+        .trim()
+        .parse()
+        .map_err(|_| SecretSharingError::InvalidEncoding("invalid share index"))?;
+    Ok((index, bigint_from_hex(value)?))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::encoding::{
+        bigint_from_hex, bigint_to_hex, bytes_from_secret, secret_from_bytes,
+        share_from_fixed_bytes, share_from_hex, share_to_fixed_bytes, share_to_hex,
+    };
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn fixed_bytes_round_trip_test() {
+        let share = (3, BigInt::from(0x0102_0304));
+        let encoded = share_to_fixed_bytes(&share, 6).unwrap();
+        assert_eq!(
+            encoded,
+            (3, vec![0, 0, 1, 2, 3, 4]),
+            "Value should be left padded"
+        );
+        assert_eq!(
+            share_from_fixed_bytes(&encoded),
+            share,
+            "Share should round trip"
+        );
+    }
+
+    #[test]
+    fn fixed_bytes_leading_zeros_test() {
+        // stored column with leading zeros decodes to a small value and re-encodes identically
+        let stored = (1, vec![0, 0, 0, 5]);
+        let share = share_from_fixed_bytes(&stored);
+        assert_eq!(share.1, BigInt::from(5));
+        assert_eq!(
+            share_to_fixed_bytes(&share, 4).unwrap(),
+            stored,
+            "Leading zeros should be preserved"
+        );
+        assert_eq!(
+            share_to_fixed_bytes(&(2, BigInt::from(0)), 4).unwrap(),
+            (2, vec![0, 0, 0, 0]),
+            "Zero should encode as all zero bytes"
+        );
+    }
+
+    #[test]
+    fn fixed_bytes_real_shares_test() {
+        // high degree polynomials and large indices still give shares below the prime
+        for prime in [None, Some((BigInt::from(1) << 127) - 1)] {
+            let mut shamir = ShamirSecretSharing::new(10, 40, prime).unwrap();
+            let width = shamir.share_byte_len();
+            assert_eq!(width, shamir.prime.bits().div_ceil(8) as usize);
+            let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+            let decoded: Vec<(usize, BigInt)> = shares
+                .iter()
+                .map(|share| {
+                    let encoded = share_to_fixed_bytes(share, width).unwrap();
+                    assert_eq!(encoded.1.len(), width);
+                    share_from_fixed_bytes(&encoded)
+                })
+                .collect();
+            assert_eq!(decoded, shares, "Every real share should fit the width");
+            assert_eq!(
+                shamir.reconstruct_tuples(&decoded[30..]).unwrap(),
+                BigInt::from(1234)
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_bytes_too_wide_test() {
+        let share = (1, BigInt::from(0x0100_0000));
+        assert!(
+            share_to_fixed_bytes(&share, 3).is_err(),
+            "Value wider than width should be rejected"
+        );
+        assert!(
+            share_to_fixed_bytes(&(1, BigInt::from(-1)), 4).is_err(),
+            "Negative value should be rejected"
+        );
+    }
+
+    #[test]
+    fn secret_bytes_leading_zero_test() {
+        let secret = secret_from_bytes(&[0x00, 0x01]);
+        assert_eq!(
+            bytes_from_secret(&secret).unwrap(),
+            vec![0x00, 0x01],
+            "Leading zero byte should survive the round trip"
+        );
+        assert_eq!(
+            bytes_from_secret(&secret_from_bytes(&[])).unwrap(),
+            Vec::<u8>::new()
+        );
+    }
+
+    #[test]
+    fn secret_bytes_missing_marker_test() {
+        // 0x01ff would otherwise decode as [0xff], 0x02ff as [0x02, 0xff]
+        for value in [0x02ff, 0xff, 0, -0x01ff] {
+            assert_eq!(
+                bytes_from_secret(&BigInt::from(value)),
+                Err(SecretSharingError::InvalidEncoding("missing secret marker")),
+                "{} has no marker",
+                value
+            );
+        }
+        assert_eq!(
+            bytes_from_secret(&BigInt::from(0x01ff)).unwrap(),
+            vec![0xff]
+        );
+    }
+
+    #[test]
+    fn secret_bytes_through_sharing_test() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+        let secret = secret_from_bytes(&bytes);
+        let prime = BigInt::parse_bytes(b"170141183460469231731687303715884105727", 10).unwrap();
+        let mut shamir = ShamirSecretSharing::new(3, 5, Some(prime)).unwrap();
+        let shares = shamir.generate_shares(secret).unwrap();
+        assert_eq!(
+            bytes_from_secret(&shamir.reconstruct(&shares[2..]).unwrap()).unwrap(),
+            bytes.to_vec(),
+            "4 byte secret should round trip through sharing"
+        );
+    }
+
+    #[test]
+    fn hex_share_round_trip_test() {
+        let share = (4, BigInt::from(0xdead_beef_u32));
+        let encoded = share_to_hex(&share);
+        assert_eq!(encoded, "4:deadbeef");
+        assert_eq!(share_from_hex(&encoded).unwrap(), share);
+        assert_eq!(
+            share_from_hex(" 4 : 0xDEADBEEF ").unwrap(),
+            share,
+            "Whitespace, prefix and upper case should be accepted"
+        );
+        assert_eq!(bigint_to_hex(&BigInt::from(0)), "0");
+        assert_eq!(bigint_from_hex("0").unwrap(), BigInt::from(0));
+    }
+
+    #[test]
+    fn hex_share_invalid_test() {
+        assert_eq!(
+            share_from_hex("4deadbeef"),
+            Err(SecretSharingError::InvalidEncoding(
+                "share is not index:hexvalue"
+            ))
+        );
+        assert_eq!(
+            share_from_hex("x:ff"),
+            Err(SecretSharingError::InvalidEncoding("invalid share index"))
+        );
+        for value in ["", "0x", "-ff", "fg"] {
+            assert_eq!(
+                bigint_from_hex(value),
+                Err(SecretSharingError::InvalidEncoding("invalid hex value")),
+                "{:?} should be rejected",
+                value
+            );
+        }
+    }
+}
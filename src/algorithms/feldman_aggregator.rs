@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
+
+use super::feldman_vss::{verify_share, CommitmentGroup, FeldmanResponse};
+use crate::error::SecretSharingError;
+
+pub struct FeldmanAggregator {
+    // additive combination of several dealers' feldman dealings over the same group
+    group: CommitmentGroup,
+    dealings: Vec<FeldmanResponse>,
+}
+
+impl FeldmanAggregator {
+    pub fn new(group: CommitmentGroup) -> Self {
+        Self {
+            group,
+            dealings: Vec::new(),
+        }
+    }
+
+    // dealings have to share the threshold (commitment count) and share indices
+    pub fn add_dealing(&mut self, dealing: FeldmanResponse) -> Result<(), SecretSharingError> {
+        if let Some(first) = self.dealings.first() {
+            if first.committments.len() != dealing.committments.len() {
+                return Err(SecretSharingError::IncompatibleDealing(
+                    "commitment counts differ",
+                ));
+            }
+            let same_indices = first.shares.len() == dealing.shares.len()
+                && first
+                    .shares
+                    .iter()
+                    .zip(dealing.shares.iter())
+                    .all(|(a, b)| a.0 == b.0);
+            if !same_indices {
+                return Err(SecretSharingError::IncompatibleDealing(
+                    "share indices differ",
+                ));
+            }
+        }
+        self.dealings.push(dealing);
+        Ok(())
+    }
+
+    // sum shares per index mod q and multiply commitments per degree mod P
+    pub fn aggregate(&self) -> Result<FeldmanResponse, SecretSharingError> {
+        let (first, rest) = self
+            .dealings
+            .split_first()
+            .ok_or(SecretSharingError::IncompatibleDealing("no dealings added"))?;
+        let mut shares = first.shares.clone();
+        let mut committments = first.committments.clone();
+        for dealing in rest {
+            for (share, (_, value)) in shares.iter_mut().zip(dealing.shares.iter()) {
+                share.1 = (&share.1 + value) % &self.group.order;
+            }
+            for (committment, other) in committments.iter_mut().zip(dealing.committments.iter()) {
+                *committment = (&*committment * other) % &self.group.modulus;
+            }
+        }
+        Ok(FeldmanResponse {
+            shares,
+            committments,
+        })
+    }
+
+    // validate a share of the aggregate against the aggregate commitments
+    pub fn validate_share(&self, aggregate: &FeldmanResponse, share: (usize, BigInt)) -> bool {
+        verify_share(&self.group, &aggregate.committments, share)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::feldman_aggregator::FeldmanAggregator;
+    use crate::algorithms::feldman_vss::{CommitmentGroup, FeldmanVSS};
+    use crate::algorithms::shamir_secret_sharing::default_prime;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn aggregate_three_dealings_test() {
+        let prime = default_prime();
+        let mut aggregator = FeldmanAggregator::new(CommitmentGroup::for_prime(&prime));
+        let secrets = [111, 222, 333];
+        for secret in secrets {
+            let mut dealer = FeldmanVSS::new(3, 5, Some(prime.clone())).unwrap();
+            let dealing = dealer.generate_shares(BigInt::from(secret)).unwrap();
+            aggregator.add_dealing(dealing).unwrap();
+        }
+
+        let aggregate = aggregator.aggregate().unwrap();
+        for share in aggregate.shares.iter() {
+            assert!(
+                aggregator.validate_share(&aggregate, share.clone()),
+                "Summed share should verify against the product of commitments"
+            );
+        }
+
+        let combiner = FeldmanVSS::new(3, 5, Some(prime)).unwrap();
+        assert_eq!(
+            combiner.reconstruct(&aggregate.shares).unwrap(),
+            BigInt::from(666),
+            "Aggregate shares should reconstruct the sum of the secrets"
+        );
+    }
+
+    #[test]
+    fn incompatible_dealing_test() {
+        let prime = default_prime();
+        let mut aggregator = FeldmanAggregator::new(CommitmentGroup::for_prime(&prime));
+        let mut dealer = FeldmanVSS::new(3, 5, Some(prime.clone())).unwrap();
+        aggregator
+            .add_dealing(dealer.generate_shares(BigInt::from(1)).unwrap())
+            .unwrap();
+
+        let mut other = FeldmanVSS::new(2, 5, Some(prime)).unwrap();
+        assert!(
+            aggregator
+                .add_dealing(other.generate_shares(BigInt::from(1)).unwrap())
+                .is_err(),
+            "Dealing with a different threshold should be rejected"
+        );
+    }
+}
@@ -1,9 +1,20 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use rayon::prelude::*;
 
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
+use rand::Rng;
+#[cfg(feature = "std")]
 use rayon::iter::IntoParallelIterator;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use super::shamir_secret_sharing::ShamirSecretSharing;
+use super::primality::is_prime;
+use super::shamir_secret_sharing::{
+    check_congruent_indices, constant_time_eq, default_prime, ShamirSecretSharing,
+};
+use crate::error::SecretSharingError;
 
 #[derive(Debug)]
 pub struct FeldmanResponse {
@@ -11,11 +22,215 @@ pub struct FeldmanResponse {
     pub committments: Vec<BigInt>,
 }
 
+impl FeldmanResponse {
+    // wire format independent of serde: a u32 count, then every commitment as a u32 byte
+    // length and its big-endian magnitude. all integers are big-endian
+    pub fn commitments_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.committments.len() as u32).to_be_bytes().to_vec();
+        for committment in self.committments.iter() {
+            let (_, value) = committment.to_bytes_be();
+            bytes.extend_from_slice(&(value.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(&value);
+        }
+        bytes
+    }
+
+    // inverse of commitments_to_bytes, truncated input and trailing bytes are errors
+    pub fn commitments_from_bytes(bytes: &[u8]) -> Result<Vec<BigInt>, SecretSharingError> {
+        let truncated = SecretSharingError::InvalidEncoding("truncated commitments");
+        let mut rest = bytes;
+        let mut take = |n: usize| {
+            if rest.len() < n {
+                return Err(truncated.clone());
+            }
+            let (head, tail) = rest.split_at(n);
+            rest = tail;
+            Ok(head)
+        };
+        let count = u32::from_be_bytes(take(4)?.try_into().unwrap());
+        let mut committments = Vec::new();
+        for _ in 0..count {
+            let len = u32::from_be_bytes(take(4)?.try_into().unwrap()) as usize;
+            committments.push(BigInt::from_bytes_be(Sign::Plus, take(len)?));
+        }
+        if !rest.is_empty() {
+            return Err(SecretSharingError::InvalidEncoding(
+                "trailing bytes after commitments",
+            ));
+        }
+        Ok(committments)
+    }
+}
+
+// group the commitments live in: the subgroup of order q (the share prime) of Z_P^*, where
+// P = k * q + 1 is the first prime of that form with k even. shares and coefficients are
+// exponents mod q, so g^share == prod Cj^(i^j) holds exactly and no exponent falls into a
+// small subgroup the way it does with g = 2 mod 2^31 - 1 (order 31)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentGroup {
+    pub modulus: BigInt,
+    pub order: BigInt,
+    pub generator: BigInt,
+}
+
+impl CommitmentGroup {
+    // group for the share prime with the default generator h^k, h the first of 2, 3, .. that
+    // does not map to 1. such a g has order exactly q since g^q = h^(P-1) = 1
+    pub fn for_prime(order: &BigInt) -> Self {
+        let (modulus, cofactor) = commitment_modulus(order);
+        let one = BigInt::from(1);
+        let mut h = BigInt::from(2);
+        let generator = loop {
+            let g = h.modpow(&cofactor, &modulus);
+            if g != one {
+                break g;
+            }
+            h += 1;
+        };
+        Self {
+            modulus,
+            order: order.clone(),
+            generator,
+        }
+    }
+
+    // same group with a caller chosen generator, rejected unless it generates the order q
+    // subgroup. the default generator passes the same check
+    pub fn with_generator(order: &BigInt, generator: BigInt) -> Result<Self, SecretSharingError> {
+        let group = Self {
+            generator,
+            ..Self::for_prime(order)
+        };
+        if !group.is_valid_generator(&group.generator) {
+            return Err(SecretSharingError::InvalidGenerator {
+                generator: group.generator,
+            });
+        }
+        Ok(group)
+    }
+
+    // 1 < g < P and g^q == 1 mod P, q prime so g has order exactly q
+    pub fn is_valid_generator(&self, generator: &BigInt) -> bool {
+        let one = BigInt::from(1);
+        *generator > one
+            && *generator < self.modulus
+            && generator.modpow(&self.order, &self.modulus) == one
+    }
+
+    // element of the order q subgroup nobody knows a discrete log of: sha256(domain, counter,
+    // block) expanded to 128 bits past the modulus, reduced mod P and raised to the cofactor.
+    // the first counter giving an element other than 1 wins, so anyone can rederive it
+    pub fn hash_to_group(&self, domain: &[u8]) -> BigInt {
+        let cofactor = (&self.modulus - 1u32) / &self.order;
+        let blocks = (self.modulus.bits() + 128).div_ceil(256);
+        let one = BigInt::from(1);
+        (0u64..)
+            .map(|counter| {
+                let mut digest = Vec::new();
+                for block in 0..blocks {
+                    let mut hasher = Sha256::new();
+                    hasher.update((domain.len() as u64).to_be_bytes());
+                    hasher.update(domain);
+                    hasher.update(counter.to_be_bytes());
+                    hasher.update(block.to_be_bytes());
+                    digest.extend_from_slice(&hasher.finalize());
+                }
+                BigInt::from_bytes_be(Sign::Plus, &digest).modpow(&cofactor, &self.modulus)
+            })
+            .find(|h| *h != one)
+            .expect("some counter maps to a non identity element")
+    }
+
+    // g^exponent mod P, the exponent reduced mod q first so negative values work too
+    pub fn commit(&self, exponent: &BigInt) -> BigInt {
+        self.generator
+            .modpow(&self.reduce_exponent(exponent), &self.modulus)
+    }
+
+    fn reduce_exponent(&self, exponent: &BigInt) -> BigInt {
+        ((exponent % &self.order) + &self.order) % &self.order
+    }
+}
+
+// smallest prime P = k * q + 1 and its cofactor k. q is odd, so only even k can give a prime
+fn commitment_modulus(order: &BigInt) -> (BigInt, BigInt) {
+    let mut cofactor = BigInt::from(2);
+    loop {
+        let modulus = order * &cofactor + 1;
+        if is_prime(&modulus) {
+            return (modulus, cofactor);
+        }
+        cofactor += 2;
+    }
+}
+
+// public part of a dealing, safe to publish in a transparency log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub group: CommitmentGroup,
+    pub threshold: usize,
+    pub total_shares: usize,
+    pub committments: Vec<BigInt>,
+    pub parameter_hash: [u8; 32],
+}
+
+impl AuditRecord {
+    // check a private share against the published commitments. a record has to carry exactly
+    // one commitment per coefficient, an empty or short list commits to nothing
+    pub fn verify_share(&self, share: (usize, BigInt)) -> bool {
+        if self.parameter_hash != self.compute_parameter_hash()
+            || self.committments.is_empty()
+            || self.committments.len() != self.threshold
+            || !self.group.is_valid_generator(&self.group.generator)
+        {
+            return false;
+        }
+        verify_share(&self.group, &self.committments, share)
+    }
+
+    // a recipient checks the dealer committed to the same (t, n) it was told about,
+    // so a dealer can't claim different parameters to different recipients
+    pub fn verify_parameters(&self, threshold: usize, total_shares: usize) -> bool {
+        self.parameter_hash == self.compute_parameter_hash()
+            && self.threshold == threshold
+            && self.total_shares == total_shares
+            && self.committments.len() == threshold
+    }
+
+    // sha256 over the public parameters, every field length prefixed
+    fn compute_parameter_hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for value in [
+            &self.group.modulus,
+            &self.group.order,
+            &self.group.generator,
+        ] {
+            let bytes = value.to_signed_bytes_be();
+            hasher.update((bytes.len() as u64).to_be_bytes());
+            hasher.update(bytes);
+        }
+        hasher.update((self.threshold as u64).to_be_bytes());
+        hasher.update((self.total_shares as u64).to_be_bytes());
+        hasher.finalize().into()
+    }
+}
+
+// publicly checkable reconstruction: secret * denominator == sum coefficients[k] * share_k mod
+// the prime, where coefficients / denominator are the lagrange coefficients at zero
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReconstructionWitness {
+    pub indices: Vec<usize>,
+    pub coefficients: Vec<BigInt>,
+    pub denominator: BigInt,
+}
+
 pub struct FeldmanVSS {
     // feldmanvss is sss with ability to verify the shares through committments
     pub committments: Vec<BigInt>,
-    generator: BigInt,
+    group: CommitmentGroup,
     shamir: ShamirSecretSharing,
+    // check g^secret == C0 after every reconstruction
+    strict: bool,
 }
 
 impl FeldmanVSS {
@@ -23,79 +238,436 @@ impl FeldmanVSS {
         threshold: usize,
         total_shares: usize,
         prime: Option<BigInt>,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, SecretSharingError> {
+        Self::new_with_generator(threshold, total_shares, prime, None)
+    }
+
+    // same as new with a caller chosen generator, which has to generate the order q subgroup
+    // of the commitment group. none picks the derived default generator
+    pub fn new_with_generator(
+        threshold: usize,
+        total_shares: usize,
+        prime: Option<BigInt>,
+        generator: Option<BigInt>,
+    ) -> Result<Self, SecretSharingError> {
         if threshold > total_shares {
-            return Err("Threshold has to be less than total shares!".to_string());
+            return Err(SecretSharingError::ThresholdExceedsShares {
+                threshold,
+                total_shares,
+            });
         }
 
-        let prime = if let Some(p) = prime {
-            p
-        } else {
-            BigInt::from(2147483647)
-        };
+        let prime = prime.unwrap_or_else(default_prime);
 
         if prime <= BigInt::from(0) {
-            return Err("Prime should not less than 1".to_string());
+            return Err(SecretSharingError::NonPrimeModulus { modulus: prime });
         }
 
         // shamir object to perform sss operations
-        let shamir = ShamirSecretSharing::new(threshold, total_shares, Some(prime)).unwrap();
+        let shamir = ShamirSecretSharing::new(threshold, total_shares, Some(prime))?;
+
+        let group = match generator {
+            Some(generator) => CommitmentGroup::with_generator(&shamir.prime, generator)?,
+            None => CommitmentGroup::for_prime(&shamir.prime),
+        };
 
         Ok(Self {
-            generator: BigInt::from(2),
+            group,
             committments: Vec::new(),
             shamir,
+            strict: false,
         })
     }
 
+    // turn the post reconstruction commitment check on or off
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    pub fn generator(&self) -> &BigInt {
+        &self.group.generator
+    }
+
+    pub fn group(&self) -> &CommitmentGroup {
+        &self.group
+    }
+
+    pub fn prime(&self) -> &BigInt {
+        &self.shamir.prime
+    }
+
     // generate Ci committments for verification of shares
     fn generate_committments(&mut self) {
-        let coefficients = &self.shamir.coefficients;
-        self.committments = (0..coefficients.len())
-            .into_par_iter()
-            .map(|i| self.generator.modpow(&coefficients[i], &self.shamir.prime))
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+        let coefficients = &self.shamir.polynomial.coefficients;
+        #[cfg(feature = "std")]
+        let indices = (0..coefficients.len()).into_par_iter();
+        #[cfg(not(feature = "std"))]
+        let indices = 0..coefficients.len();
+        self.committments = indices
+            .map(|i| self.group.commit(&coefficients[i]))
             .collect();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            commitments = self.committments.len(),
+            elapsed_us = started.elapsed().as_micros() as u64,
+            "generated commitments"
+        );
     }
 
     // call sss share generation logic
-    pub fn generate_shares(&mut self, secret: BigInt) -> Result<FeldmanResponse, String> {
-        let shares = self.shamir.generate_shares(secret.clone()).unwrap();
-        self.generate_committments();
+    #[cfg(feature = "std")]
+    pub fn generate_shares(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<FeldmanResponse, SecretSharingError> {
+        self.generate_shares_with_rng(secret, &mut rand::thread_rng())
+    }
+
+    // generate_shares drawing the polynomial from the caller's rng
+    pub fn generate_shares_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        secret: BigInt,
+        rng: &mut R,
+    ) -> Result<FeldmanResponse, SecretSharingError> {
+        let shares = self.shamir.generate_shares_with_rng(secret, rng)?;
+        self.committments.clear();
         let shares = FeldmanResponse {
             shares,
-            committments: self.committments.clone(),
+            committments: self.commitments().to_vec(),
         };
         Ok(shares)
     }
 
+    // shares only, the threshold modpows for the commitments are deferred until commitments()
+    // is called. until then validate_shares and friends have nothing to check against and
+    // reject every share
+    #[cfg(feature = "std")]
+    pub fn generate_shares_lazy(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        let shares = self.shamir.generate_shares_tuples(secret)?;
+        self.committments.clear();
+        Ok(shares)
+    }
+
+    // commitments of the current polynomial, computed on first access and cached until the
+    // next dealing. empty when nothing was dealt yet
+    pub fn commitments(&mut self) -> &[BigInt] {
+        if self.committments.is_empty() && !self.shamir.polynomial.coefficients.is_empty() {
+            self.generate_committments();
+        }
+        &self.committments
+    }
+
     // use committments to validate shares
     pub fn validate_shares(&self, share: (usize, BigInt)) -> bool {
-        // share is in the form (i,v)
-        let i = BigInt::from(share.0);
-        let v = share.1;
-        let lhs = self.generator.modpow(&v, &self.shamir.prime);
-        let mut rhs = self.committments[0].clone();
-        for it in 1..self.committments.len() {
-            // i^j
-            let exp_term = i.modpow(&BigInt::from(it), &self.shamir.prime);
-            // Ci^(i^j)
-            let term = self.committments[it].modpow(&BigInt::from(exp_term), &self.shamir.prime);
-            rhs = (rhs * term) % &self.shamir.prime;
+        verify_share(&self.group, &self.committments, share)
+    }
+
+    // indices of every share failing verification, empty when all of them are valid
+    pub fn validate_all(&self, shares: &[(usize, BigInt)]) -> Vec<usize> {
+        #[cfg(feature = "std")]
+        let shares = shares.par_iter();
+        #[cfg(not(feature = "std"))]
+        let shares = shares.iter();
+        shares
+            .filter(|share| !self.validate_shares((*share).clone()))
+            .map(|(index, _)| *index)
+            .collect()
+    }
+
+    // right hand side of the verification equation, external verifiers compare it with g^share.
+    // none until commitments exist, e.g. after generate_shares_lazy before commitments()
+    pub fn expected_commitment_at(&self, index: usize) -> Option<BigInt> {
+        committment_product(
+            &self.group.modulus,
+            &self.group.order,
+            &self.committments,
+            index,
+        )
+    }
+
+    // sha256 over the count and every length prefixed commitment. the dealer publishes it so
+    // each verifier can check it received the same commitments as everyone else
+    pub fn commitments_digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update((self.committments.len() as u64).to_be_bytes());
+        for committment in self.committments.iter() {
+            let bytes = committment.to_signed_bytes_be();
+            hasher.update((bytes.len() as u64).to_be_bytes());
+            hasher.update(bytes);
+        }
+        hasher.finalize().into()
+    }
+
+    pub fn verify_commitments_digest(&self, expected: &[u8; 32]) -> bool {
+        self.commitments_digest() == *expected
+    }
+
+    // everything a third party needs to verify shares, without the shares themselves
+    pub fn public_audit_record(&self) -> AuditRecord {
+        let mut record = AuditRecord {
+            group: self.group.clone(),
+            threshold: self.shamir.threshold,
+            total_shares: self.shamir.total_shares,
+            committments: self.committments.clone(),
+            parameter_hash: [0; 32],
+        };
+        record.parameter_hash = record.compute_parameter_hash();
+        record
+    }
+
+    // commitments live in Z_P^*, which has no point compression like an elliptic curve group.
+    // the compact form is every commitment as a fixed width big-endian element mod P
+    pub fn compress_committments(&self) -> Vec<u8> {
+        let width = field_width(&self.group.modulus);
+        let mut bytes = Vec::with_capacity(width * self.committments.len());
+        for committment in self.committments.iter() {
+            let (_, value) = committment.to_bytes_be();
+            bytes.resize(bytes.len() + width - value.len(), 0);
+            bytes.extend_from_slice(&value);
+        }
+        bytes
+    }
+
+    // inverse of compress_committments, every element has to be reduced mod the group modulus
+    pub fn decompress_committments(
+        bytes: &[u8],
+        modulus: &BigInt,
+    ) -> Result<Vec<BigInt>, SecretSharingError> {
+        let width = field_width(modulus);
+        if bytes.is_empty() || !bytes.len().is_multiple_of(width) {
+            return Err(SecretSharingError::InvalidEncoding(
+                "compressed commitments have the wrong length",
+            ));
         }
+        let committments: Vec<BigInt> = bytes
+            .chunks(width)
+            .map(|chunk| BigInt::from_bytes_be(Sign::Plus, chunk))
+            .collect();
+        if committments.iter().any(|c| c >= modulus) {
+            return Err(SecretSharingError::InvalidEncoding(
+                "commitment is not a field element",
+            ));
+        }
+        Ok(committments)
+    }
+
+    // validate a share against commitments received in compressed form
+    pub fn validate_compressed_shares(&self, compressed: &[u8], share: (usize, BigInt)) -> bool {
+        match Self::decompress_committments(compressed, &self.group.modulus) {
+            Ok(committments) => verify_share(&self.group, &committments, share),
+            Err(_) => false,
+        }
+    }
+
+    // drop every share that fails verification against the commitments and reconstruct from
+    // the rest, so a corrupted share cannot poison the result
+    pub fn reconstruct_verified(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<BigInt, SecretSharingError> {
+        let valid: Vec<(usize, BigInt)> = shares
+            .iter()
+            .filter(|share| self.validate_shares((*share).clone()))
+            .cloned()
+            .collect();
+        if valid.len() < self.shamir.threshold {
+            return Err(SecretSharingError::InsufficientShares {
+                got: valid.len(),
+                need: self.shamir.threshold,
+            });
+        }
+        self.reconstruct(&valid)
+    }
+
+    // all or nothing: every share has to verify, otherwise the first failing index is reported
+    // and nothing is reconstructed. validation reuses the commitments already held in memory
+    pub fn validate_and_reconstruct(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<BigInt, SecretSharingError> {
+        if let Some((index, _)) = shares
+            .iter()
+            .find(|share| !self.validate_shares((*share).clone()))
+        {
+            return Err(SecretSharingError::ShareVerificationFailed { index: *index });
+        }
+        self.reconstruct(shares)
+    }
+
+    // reconstruct and return the witness for the interpolated shares (every offered share)
+    pub fn reconstruct_witness(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<(BigInt, ReconstructionWitness), SecretSharingError> {
+        let secret = self.reconstruct(shares)?;
+        let indices: Vec<usize> = shares.iter().map(|(i, _)| *i).collect();
+        let (coefficients, denominator) = witness_terms(&indices);
+        let witness = ReconstructionWitness {
+            indices,
+            coefficients,
+            denominator,
+        };
+        Ok((secret, witness))
+    }
+
+    // check g^(secret * denominator) == prod share_commitments[k]^coefficients[k] where
+    // share_commitments[k] = g^share for witness.indices[k], e.g. from expected_commitment_at.
+    // the coefficients and denominator are recomputed from the indices rather than trusted, so
+    // a zeroed or rescaled witness cannot make every secret verify
+    pub fn verify_witness(
+        &self,
+        secret: &BigInt,
+        witness: &ReconstructionWitness,
+        share_commitments: &[BigInt],
+    ) -> bool {
+        let indices = &witness.indices;
+        if indices.len() < self.shamir.threshold
+            || indices.len() != witness.coefficients.len()
+            || indices.len() != share_commitments.len()
+        {
+            return false;
+        }
+        // duplicate or congruent indices would make the denominator vanish mod q
+        let shares: Vec<(usize, BigInt)> = indices.iter().map(|i| (*i, BigInt::from(0))).collect();
+        if check_congruent_indices(&shares, &self.group.order).is_err() {
+            return false;
+        }
+        let (coefficients, denominator) = witness_terms(indices);
+        if coefficients != witness.coefficients
+            || denominator != witness.denominator
+            || self.group.reduce_exponent(&denominator) == BigInt::from(0)
+        {
+            return false;
+        }
+        let group = &self.group;
+        let lhs = group.commit(&(secret * &witness.denominator));
+        let rhs = share_commitments
+            .iter()
+            .zip(witness.coefficients.iter())
+            .fold(BigInt::from(1), |acc, (c, l)| {
+                acc * c.modpow(&group.reduce_exponent(l), &group.modulus) % &group.modulus
+            });
         lhs == rhs
     }
-    pub fn reconstruct(&self, shares: &Vec<(usize, BigInt)>) -> Result<BigInt, String> {
-        self.shamir.reconstruct(shares)
+
+    // share check from public data alone, for a combiner that got the commitments from the
+    // dealer but holds no FeldmanVSS instance
+    pub fn verify(commitments: &[BigInt], group: &CommitmentGroup, share: (usize, BigInt)) -> bool {
+        !commitments.is_empty() && verify_share(group, commitments, share)
+    }
+
+    // one commitment per coefficient, so a commitment vector alone gives the threshold
+    pub fn threshold_from_commitments(commitments: &[BigInt]) -> usize {
+        commitments.len()
+    }
+
+    pub fn reconstruct(&self, shares: &[(usize, BigInt)]) -> Result<BigInt, SecretSharingError> {
+        // the published commitments are what participants check, hold reconstruction to them
+        let need = Self::threshold_from_commitments(&self.committments);
+        if shares.len() < need {
+            return Err(SecretSharingError::InsufficientShares {
+                got: shares.len(),
+                need,
+            });
+        }
+        let secret = self.shamir.reconstruct_tuples(shares)?;
+        if self.strict && !self.verify_secret(&secret) {
+            return Err(SecretSharingError::CommitmentMismatch);
+        }
+        Ok(secret)
+    }
+
+    // check a recovered a0 against the dealer's C0 = g^a0
+    pub fn verify_secret(&self, secret: &BigInt) -> bool {
+        self.committments
+            .first()
+            .is_some_and(|c0| constant_time_eq(c0, &self.group.commit(secret)))
+    }
+}
+
+// lagrange coefficients at zero scaled to integers for the given indices: denominator is the
+// absolute vandermonde product, which every lagrange denominator prod (xi - xj) divides
+fn witness_terms(indices: &[usize]) -> (Vec<BigInt>, BigInt) {
+    let xs: Vec<BigInt> = indices.iter().map(|x| BigInt::from(*x)).collect();
+    let mut denominator = BigInt::from(1);
+    for i in 0..xs.len() {
+        for j in i + 1..xs.len() {
+            denominator *= &xs[j] - &xs[i];
+        }
+    }
+    if denominator < BigInt::from(0) {
+        denominator = -denominator;
     }
+    let coefficients = (0..xs.len())
+        .map(|i| {
+            let mut num = BigInt::from(1);
+            let mut denom = BigInt::from(1);
+            for j in 0..xs.len() {
+                if i != j {
+                    num *= -&xs[j];
+                    denom *= &xs[i] - &xs[j];
+                }
+            }
+            num * &denominator / denom
+        })
+        .collect();
+    (coefficients, denominator)
 }
 
-#[cfg(test)]
+// check g^v == prod Cj^(i^j) for a share (i,v). no commitments verify nothing
+pub(crate) fn verify_share(
+    group: &CommitmentGroup,
+    committments: &[BigInt],
+    share: (usize, BigInt),
+) -> bool {
+    let Some(rhs) = committment_product(&group.modulus, &group.order, committments, share.0) else {
+        return false;
+    };
+    let lhs = group.commit(&share.1);
+    constant_time_eq(&lhs, &rhs)
+}
+
+// prod Cj^(i^j) mod modulus, the value g^f(i) should equal. exponents are reduced mod the
+// generator's order. none for an empty commitment list, which commits to no polynomial at all
+pub(crate) fn committment_product(
+    modulus: &BigInt,
+    order: &BigInt,
+    committments: &[BigInt],
+    index: usize,
+) -> Option<BigInt> {
+    let i = BigInt::from(index);
+    let mut rhs = committments.first()?.clone();
+    for (it, committment) in committments.iter().enumerate().skip(1) {
+        // i^j
+        let exp_term = i.modpow(&BigInt::from(it), order);
+        // Ci^(i^j)
+        let term = committment.modpow(&exp_term, modulus);
+        rhs = (rhs * term) % modulus;
+    }
+    Some(rhs)
+}
+
+// bytes needed to hold any element mod the given modulus
+fn field_width(prime: &BigInt) -> usize {
+    prime.bits().div_ceil(8) as usize
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use crate::algorithms::feldman_vss::FeldmanVSS;
+    use crate::algorithms::feldman_vss::{
+        AuditRecord, CommitmentGroup, FeldmanResponse, FeldmanVSS, ReconstructionWitness,
+    };
+    use crate::algorithms::shamir_secret_sharing::{default_prime, ShamirSecretSharing};
+    use crate::error::SecretSharingError;
     use num_bigint::BigInt;
 
     fn create_feldman_vss(threshold: usize, total_shares: usize) -> FeldmanVSS {
-        let prime = BigInt::from(2147483647); // Prime number
+        let prime = default_prime();
         FeldmanVSS::new(threshold, total_shares, Some(prime)).unwrap()
     }
 
@@ -103,7 +675,7 @@ mod tests {
     fn test_invalid_threshold() {
         let threshold = 6; // Threshold larger than total_shares
         let total_shares = 5;
-        let prime = BigInt::from(2147483647); // default Prime number
+        let prime = default_prime();
 
         let result = FeldmanVSS::new(threshold, total_shares, Some(prime));
         // Expecting an error because threshold is larger than total_shares
@@ -113,6 +685,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_zero_threshold() {
+        let result = FeldmanVSS::new(0, 5, None);
+        assert!(
+            matches!(result, Err(SecretSharingError::ZeroThreshold)),
+            "Expected an error due to zero threshold"
+        );
+    }
+
     #[test]
     fn test_generate_shares() {
         let threshold = 3;
@@ -183,9 +764,7 @@ mod tests {
         let response = vss.generate_shares(secret.clone()).unwrap();
 
         // Reconstruct the secret using the first `threshold` number of shares
-        let reconstructed_secret = vss
-            .reconstruct(&response.shares[0..threshold].to_vec())
-            .unwrap();
+        let reconstructed_secret = vss.reconstruct(&response.shares[0..threshold]).unwrap();
 
         // Ensure the reconstructed secret matches the original secret
         assert_eq!(
@@ -241,10 +820,593 @@ mod tests {
         let mut vss = create_feldman_vss(threshold, total_shares);
         let response = vss.generate_shares(secret.clone()).unwrap();
         // Try to reconstruct the secret with fewer than the required shares
-        let reconstructed_secret = vss.reconstruct(&response.shares[0..threshold - 1].to_vec());
+        let reconstructed_secret = vss.reconstruct(&response.shares[0..threshold - 1]);
         assert!(
             reconstructed_secret.is_err(),
             "Reconstruction should fail with fewer than `threshold` shares"
         );
     }
+
+    #[test]
+    fn test_public_audit_record() {
+        let threshold = 3;
+        let total_shares = 5;
+        let mut vss = create_feldman_vss(threshold, total_shares);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+
+        // dealer publishes the record, custodian only sees the serialized form
+        let published = serde_json::to_string(&vss.public_audit_record()).unwrap();
+        let record: AuditRecord = serde_json::from_str(&published).unwrap();
+
+        let my_share = response.shares[1].clone();
+        assert!(
+            record.verify_share(my_share.clone()),
+            "Custodian's share should verify against the audit record"
+        );
+
+        let mut forged = my_share;
+        forged.1 += 1;
+        assert!(
+            !record.verify_share(forged),
+            "Forged share should not verify against the audit record"
+        );
+    }
+
+    #[test]
+    fn test_lazy_commitments() {
+        let mut vss = create_feldman_vss(3, 5);
+        assert!(
+            vss.commitments().is_empty(),
+            "Nothing dealt, nothing committed"
+        );
+        let shares = vss.generate_shares_lazy(BigInt::from(1234)).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert!(
+            vss.committments.is_empty(),
+            "Commitments should not be computed before they are requested"
+        );
+
+        let first = vss.commitments().to_vec();
+        assert_eq!(first.len(), 3);
+        assert_eq!(
+            vss.commitments(),
+            first.as_slice(),
+            "The cached commitments should be returned again"
+        );
+        for share in shares.iter() {
+            assert!(vss.validate_shares(share.clone()));
+        }
+
+        // a new dealing invalidates the cache
+        vss.generate_shares_lazy(BigInt::from(4321)).unwrap();
+        assert!(vss.committments.is_empty());
+        assert_ne!(vss.commitments(), first.as_slice());
+    }
+
+    #[test]
+    fn test_generate_shares_with_rng() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        // the no_std dealing path, the caller brings the randomness
+        let mut vss = create_feldman_vss(3, 5);
+        let first = vss
+            .generate_shares_with_rng(BigInt::from(1234), &mut ChaCha20Rng::seed_from_u64(7))
+            .unwrap();
+        assert!(vss.validate_all(&first.shares).is_empty());
+        let second = vss
+            .generate_shares_with_rng(BigInt::from(1234), &mut ChaCha20Rng::seed_from_u64(7))
+            .unwrap();
+        assert_eq!(first.shares, second.shares, "Same seed, same dealing");
+        assert_eq!(first.committments, second.committments);
+    }
+
+    #[test]
+    fn test_lazy_commitments_validation() {
+        let mut vss = create_feldman_vss(3, 5);
+        let shares = vss.generate_shares_lazy(BigInt::from(7)).unwrap();
+
+        // nothing to check against yet, every validator rejects instead of panicking
+        assert!(!vss.validate_shares(shares[0].clone()));
+        assert_eq!(vss.validate_all(&shares), vec![1, 2, 3, 4, 5]);
+        assert_eq!(vss.expected_commitment_at(1), None);
+        assert_eq!(
+            vss.validate_and_reconstruct(&shares),
+            Err(SecretSharingError::ShareVerificationFailed { index: 1 })
+        );
+        assert_eq!(
+            vss.reconstruct_verified(&shares),
+            Err(SecretSharingError::InsufficientShares { got: 0, need: 3 })
+        );
+
+        vss.commitments();
+        assert!(vss.validate_shares(shares[0].clone()));
+        assert!(vss.validate_all(&shares).is_empty());
+        assert_eq!(
+            vss.validate_and_reconstruct(&shares).unwrap(),
+            BigInt::from(7)
+        );
+        assert_eq!(vss.reconstruct_verified(&shares).unwrap(), BigInt::from(7));
+    }
+
+    #[test]
+    fn test_compressed_committments() {
+        let threshold = 3;
+        let total_shares = 5;
+        let mut vss = create_feldman_vss(threshold, total_shares);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+
+        let compressed = vss.compress_committments();
+        // 2^31 - 1 pairs with P = 46 * (2^31 - 1) + 1, a 37 bit modulus
+        assert_eq!(
+            compressed.len(),
+            5 * threshold,
+            "Each commitment should take the modulus' byte width"
+        );
+        let decompressed =
+            FeldmanVSS::decompress_committments(&compressed, &vss.group().modulus).unwrap();
+        assert_eq!(
+            decompressed, response.committments,
+            "Commitments should survive the round trip"
+        );
+
+        let share = response.shares[2].clone();
+        assert!(
+            vss.validate_compressed_shares(&compressed, share.clone()),
+            "Share should validate against the compressed commitments"
+        );
+        assert!(
+            !vss.validate_compressed_shares(&compressed[1..], share),
+            "Truncated commitments should not validate"
+        );
+    }
+
+    #[test]
+    fn test_validate_all_shares_large_indices() {
+        // i^j exceeds the prime for these indices, so the exponent must be reduced mod q
+        let threshold = 10;
+        let total_shares = 30;
+        let mut vss = create_feldman_vss(threshold, total_shares);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        for share in response.shares {
+            let index = share.0;
+            assert!(
+                vss.validate_shares(share),
+                "Share {} should be valid",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_expected_commitment_at() {
+        let mut vss = create_feldman_vss(4, 12);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        for (index, value) in response.shares {
+            assert_eq!(
+                Some(vss.group().commit(&value)),
+                vss.expected_commitment_at(index),
+                "g^share should equal the commitment product at its index"
+            );
+        }
+    }
+
+    #[test]
+    fn test_audit_record_missing_commitments() {
+        let mut vss = create_feldman_vss(3, 5);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        let mut record = vss.public_audit_record();
+        record.committments.clear();
+        let empty: AuditRecord =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+        assert!(
+            !empty.verify_share(response.shares[0].clone()),
+            "A record without commitments should verify nothing"
+        );
+
+        let mut short = vss.public_audit_record();
+        short.committments.truncate(2);
+        assert!(
+            !short.verify_share(response.shares[0].clone()),
+            "A record with fewer commitments than its threshold should be rejected"
+        );
+
+        // a zero threshold record with a matching hash still has nothing to check against
+        let mut zero = vss.public_audit_record();
+        zero.threshold = 0;
+        zero.committments.clear();
+        zero.parameter_hash = zero.compute_parameter_hash();
+        assert!(!zero.verify_share(response.shares[0].clone()));
+    }
+
+    #[test]
+    fn test_audit_record_parameters() {
+        let mut vss = create_feldman_vss(3, 5);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        let record = vss.public_audit_record();
+
+        assert!(
+            record.verify_parameters(3, 5),
+            "Recipient told 3-of-5 should accept the record"
+        );
+        assert!(
+            !record.verify_parameters(2, 5),
+            "Recipient told 2-of-5 should detect the mismatch"
+        );
+
+        // record altered after its hash was published to the log
+        let mut lying = record.clone();
+        lying.threshold = 2;
+        assert!(
+            !lying.verify_parameters(2, 5),
+            "Altered parameters should not match the committed hash"
+        );
+        assert!(
+            !lying.verify_share(response.shares[0].clone()),
+            "Shares should not verify against an altered record"
+        );
+    }
+
+    #[test]
+    fn test_strict_reconstruct() {
+        let threshold = 3;
+        let mut vss = create_feldman_vss(threshold, 5);
+        vss.set_strict(true);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            vss.reconstruct(&response.shares[0..threshold]).unwrap(),
+            BigInt::from(1234),
+            "Honest shares should pass the C0 check"
+        );
+
+        // off by one in a single share shifts the interpolated secret
+        let mut corrupted = response.shares[0..threshold].to_vec();
+        corrupted[1].1 += 1;
+        assert_eq!(
+            vss.reconstruct(&corrupted),
+            Err(SecretSharingError::CommitmentMismatch),
+            "Corrupted reconstruction should be reported"
+        );
+
+        vss.set_strict(false);
+        assert!(
+            vss.reconstruct(&corrupted).is_ok(),
+            "Non strict mode should not check the commitment"
+        );
+    }
+
+    #[test]
+    fn test_reconstruction_witness() {
+        let mut feldman = create_feldman_vss(3, 5);
+        let secret = BigInt::from(1234);
+        let response = feldman.generate_shares(secret.clone()).unwrap();
+        let shares = &response.shares[1..4];
+
+        let (recovered, witness) = feldman.reconstruct_witness(shares).unwrap();
+        assert_eq!(recovered, secret);
+        assert_eq!(witness.indices, vec![2, 3, 4]);
+        let share_commitments: Vec<BigInt> = witness
+            .indices
+            .iter()
+            .map(|i| feldman.expected_commitment_at(*i).unwrap())
+            .collect();
+        assert!(
+            feldman.verify_witness(&recovered, &witness, &share_commitments),
+            "Honest witness should verify"
+        );
+
+        assert!(
+            !feldman.verify_witness(&(&recovered + 1), &witness, &share_commitments),
+            "Witness should not verify a different secret"
+        );
+        // shifts the exponent by the secret itself, which is not a multiple of q
+        let tampered = ReconstructionWitness {
+            denominator: &witness.denominator + 1,
+            ..witness.clone()
+        };
+        assert!(
+            !feldman.verify_witness(&recovered, &tampered, &share_commitments),
+            "Tampered witness should not verify"
+        );
+    }
+
+    #[test]
+    fn test_forged_witness() {
+        let mut feldman = create_feldman_vss(3, 5);
+        let response = feldman.generate_shares(BigInt::from(1234)).unwrap();
+        let (recovered, witness) = feldman.reconstruct_witness(&response.shares[..3]).unwrap();
+        let share_commitments: Vec<BigInt> = witness
+            .indices
+            .iter()
+            .map(|i| feldman.expected_commitment_at(*i).unwrap())
+            .collect();
+        let forged_secret = &recovered + 1;
+
+        // g^0 == 1 on both sides for any secret
+        let empty = ReconstructionWitness {
+            indices: vec![],
+            coefficients: vec![],
+            denominator: BigInt::from(0),
+        };
+        assert!(!feldman.verify_witness(&forged_secret, &empty, &[]));
+        let zeroed = ReconstructionWitness {
+            coefficients: vec![BigInt::from(0); 3],
+            denominator: BigInt::from(0),
+            ..witness.clone()
+        };
+        assert!(
+            !feldman.verify_witness(&forged_secret, &zeroed, &share_commitments),
+            "A zeroed witness should not verify any secret"
+        );
+
+        // scaling everything by q keeps the equation true for every secret
+        let q = feldman.prime().clone();
+        let scaled = ReconstructionWitness {
+            coefficients: witness.coefficients.iter().map(|c| c * &q).collect(),
+            denominator: &witness.denominator * &q,
+            ..witness.clone()
+        };
+        assert!(!feldman.verify_witness(&forged_secret, &scaled, &share_commitments));
+        let doubled = ReconstructionWitness {
+            coefficients: witness.coefficients.iter().map(|c| c * 2).collect(),
+            denominator: &witness.denominator * 2,
+            ..witness.clone()
+        };
+        assert!(
+            !feldman.verify_witness(&recovered, &doubled, &share_commitments),
+            "Coefficients have to be the ones the indices give"
+        );
+
+        let duplicated = ReconstructionWitness {
+            indices: vec![1, 1, 2],
+            ..witness.clone()
+        };
+        assert!(!feldman.verify_witness(&recovered, &duplicated, &share_commitments));
+        let mut short = witness.clone();
+        short.indices.pop();
+        short.coefficients.pop();
+        assert!(
+            !feldman.verify_witness(&recovered, &short, &share_commitments[..2]),
+            "Fewer indices than the threshold should not verify"
+        );
+    }
+
+    #[test]
+    fn test_validate_all() {
+        let mut vss = create_feldman_vss(3, 7);
+        let mut shares = vss.generate_shares(BigInt::from(1234)).unwrap().shares;
+        assert!(
+            vss.validate_all(&shares).is_empty(),
+            "Honest shares should all be valid"
+        );
+
+        shares[1].1 += 1;
+        shares[5].1 += 42;
+        assert_eq!(
+            vss.validate_all(&shares),
+            vec![2, 6],
+            "Exactly the corrupted shares should be reported"
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_verified() {
+        let mut vss = create_feldman_vss(3, 6);
+        let secret = BigInt::from(1234);
+        let mut shares = vss.generate_shares(secret.clone()).unwrap().shares;
+        shares[0].1 += 7;
+        shares[2].1 -= 3;
+
+        assert_eq!(
+            vss.reconstruct_verified(&shares).unwrap(),
+            secret,
+            "Corrupted shares should be dropped before reconstruction"
+        );
+        assert_eq!(
+            vss.reconstruct_verified(&shares[..4]),
+            Err(SecretSharingError::InsufficientShares { got: 2, need: 3 }),
+            "Too few valid shares should be an error"
+        );
+    }
+
+    #[test]
+    fn test_commitments_digest() {
+        let mut dealer = create_feldman_vss(3, 5);
+        dealer.generate_shares(BigInt::from(1234)).unwrap();
+        let published = dealer.commitments_digest();
+        assert!(
+            dealer.verify_commitments_digest(&published),
+            "Verifier with the same commitments should accept"
+        );
+
+        // a second dealing stands in for the commitments sent to a different party
+        let mut other = create_feldman_vss(3, 5);
+        other.generate_shares(BigInt::from(1234)).unwrap();
+        assert_ne!(other.commitments_digest(), published);
+        assert!(
+            !other.verify_commitments_digest(&published),
+            "Verifier with different commitments should reject"
+        );
+    }
+
+    #[test]
+    fn test_default_generator() {
+        // 2 only has order 31 mod 2^31 - 1, the derived default has to be of order q
+        let prime = default_prime();
+        let group = CommitmentGroup::for_prime(&prime);
+        assert_eq!(group.modulus, &prime * 46 + 1);
+        assert!(
+            group.is_valid_generator(&group.generator),
+            "Default generator should pass the generator check"
+        );
+        let vss = create_feldman_vss(3, 5);
+        assert_eq!(vss.group(), &group);
+        assert!(FeldmanVSS::new_with_generator(
+            3,
+            5,
+            Some(prime.clone()),
+            Some(group.generator.clone())
+        )
+        .is_ok());
+
+        for small in [
+            BigInt::from(3),
+            BigInt::from(7919),
+            (BigInt::from(1) << 127) - 1,
+        ] {
+            let group = CommitmentGroup::for_prime(&small);
+            assert!(group.is_valid_generator(&group.generator));
+            assert_eq!((&group.modulus - 1) % &small, BigInt::from(0));
+        }
+    }
+
+    #[test]
+    fn test_custom_generator() {
+        // any g = h^46 != 1 mod P lies in the order q subgroup
+        let prime = default_prime();
+        let group = CommitmentGroup::for_prime(&prime);
+        let generator = BigInt::from(7).modpow(&BigInt::from(46), &group.modulus);
+        let mut vss =
+            FeldmanVSS::new_with_generator(3, 5, Some(prime.clone()), Some(generator.clone()))
+                .unwrap();
+        assert_eq!(vss.generator(), &generator);
+        let secret = BigInt::from(1234);
+        let response = vss.generate_shares(secret.clone()).unwrap();
+        assert_eq!(
+            vss.validate_all(&response.shares),
+            Vec::<usize>::new(),
+            "Shares should verify under the custom generator"
+        );
+        assert_eq!(vss.reconstruct(&response.shares[..3]).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_invalid_generator() {
+        let prime = default_prime();
+        let modulus = CommitmentGroup::for_prime(&prime).modulus;
+        // 2 and 7 generate far more than the order q subgroup, P - 1 has order 2
+        for generator in [
+            BigInt::from(0),
+            BigInt::from(1),
+            BigInt::from(2),
+            BigInt::from(7),
+            &modulus - 1,
+            modulus.clone(),
+        ] {
+            assert_eq!(
+                FeldmanVSS::new_with_generator(3, 5, Some(prime.clone()), Some(generator.clone()))
+                    .err(),
+                Some(SecretSharingError::InvalidGenerator {
+                    generator: generator.clone()
+                }),
+                "Generator {} should be rejected",
+                generator
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_and_reconstruct() {
+        let mut vss = create_feldman_vss(3, 5);
+        let secret = BigInt::from(1234);
+        let mut shares = vss.generate_shares(secret.clone()).unwrap().shares;
+        assert_eq!(vss.validate_and_reconstruct(&shares).unwrap(), secret);
+
+        shares[3].1 += 1;
+        assert_eq!(
+            vss.validate_and_reconstruct(&shares),
+            Err(SecretSharingError::ShareVerificationFailed { index: 4 }),
+            "Invalid share's index should be reported"
+        );
+    }
+
+    #[test]
+    fn verify_with_external_commitments_test() {
+        let mut vss = create_feldman_vss(3, 5);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+
+        // the combiner only ever sees these serialized values
+        let wire = serde_json::to_string(&(&response.committments, vss.group())).unwrap();
+        let (commitments, group): (Vec<BigInt>, CommitmentGroup) =
+            serde_json::from_str(&wire).unwrap();
+
+        for share in response.shares.iter() {
+            assert!(
+                FeldmanVSS::verify(&commitments, &group, share.clone()),
+                "Share {} should verify from public data",
+                share.0
+            );
+        }
+        let forged = (2, &response.shares[1].1 + 1);
+        assert!(!FeldmanVSS::verify(&commitments, &group, forged));
+        assert!(!FeldmanVSS::verify(&[], &group, response.shares[0].clone()));
+    }
+
+    #[test]
+    fn threshold_from_commitments_test() {
+        let mut vss = create_feldman_vss(4, 6);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        let threshold = FeldmanVSS::threshold_from_commitments(&response.committments);
+        assert_eq!(threshold, 4, "One commitment per coefficient");
+
+        // a combiner knowing only the commitments and the prime
+        let combiner = ShamirSecretSharing::new(threshold, 6, Some(vss.prime().clone())).unwrap();
+        assert_eq!(
+            combiner
+                .reconstruct_tuples(&response.shares[2..2 + threshold])
+                .unwrap(),
+            BigInt::from(1234)
+        );
+        assert_eq!(
+            vss.reconstruct(&response.shares[..3]),
+            Err(SecretSharingError::InsufficientShares { got: 3, need: 4 })
+        );
+    }
+
+    #[test]
+    fn commitments_wire_format_test() {
+        let mut vss = create_feldman_vss(3, 5);
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        let bytes = response.commitments_to_bytes();
+        assert_eq!(&bytes[..4], &[0, 0, 0, 3], "Count should come first");
+
+        let parsed = FeldmanResponse::commitments_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, response.committments);
+        assert!(
+            FeldmanVSS::verify(&parsed, vss.group(), response.shares[3].clone()),
+            "Parsed commitments should validate a share"
+        );
+
+        assert_eq!(
+            FeldmanResponse::commitments_from_bytes(&bytes[..bytes.len() - 1]),
+            Err(SecretSharingError::InvalidEncoding("truncated commitments"))
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            FeldmanResponse::commitments_from_bytes(&trailing),
+            Err(SecretSharingError::InvalidEncoding(
+                "trailing bytes after commitments"
+            ))
+        );
+    }
+
+    #[test]
+    fn verify_secret_test() {
+        let mut vss = create_feldman_vss(3, 5);
+        assert!(
+            !vss.verify_secret(&BigInt::from(1234)),
+            "Nothing is committed before dealing"
+        );
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        let secret = vss.reconstruct(&response.shares[1..4]).unwrap();
+        assert!(
+            vss.verify_secret(&secret),
+            "Recovered secret should match C0"
+        );
+        assert!(
+            !vss.verify_secret(&(secret + 1)),
+            "A wrong secret should not match C0"
+        );
+    }
 }
@@ -0,0 +1,119 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num_bigint::{BigInt, BigUint, Sign};
+
+use super::shamir_secret_sharing::mod_inverse;
+
+// element of the field mod prime, kept as a BigUint in [0, prime). negative inputs are reduced
+// once on the way in, so arithmetic never needs a sign fixup afterwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldElement {
+    value: BigUint,
+    prime: BigUint,
+}
+
+impl FieldElement {
+    pub fn new(value: &BigInt, prime: &BigInt) -> Self {
+        let prime = prime.magnitude().clone();
+        let magnitude = value.magnitude() % &prime;
+        let value = if value.sign() == Sign::Minus && magnitude != BigUint::from(0u8) {
+            &prime - magnitude
+        } else {
+            magnitude
+        };
+        Self { value, prime }
+    }
+
+    pub fn from_index(index: usize, prime: &BigInt) -> Self {
+        Self::new(&BigInt::from(index), prime)
+    }
+
+    pub fn zero(prime: &BigInt) -> Self {
+        Self::new(&BigInt::from(0), prime)
+    }
+
+    pub fn one(prime: &BigInt) -> Self {
+        Self::new(&BigInt::from(1), prime)
+    }
+
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.value.clone())
+    }
+
+    // None for zero, or when the modulus is not prime and the element shares a factor with it
+    pub fn inverse(&self) -> Option<Self> {
+        let prime = BigInt::from(self.prime.clone());
+        mod_inverse(&self.to_bigint(), &prime).map(|inverse| Self::new(&inverse, &prime))
+    }
+
+    fn with_value(&self, value: BigUint) -> Self {
+        Self {
+            value,
+            prime: self.prime.clone(),
+        }
+    }
+}
+
+impl Add for &FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: &FieldElement) -> FieldElement {
+        self.with_value((&self.value + &other.value) % &self.prime)
+    }
+}
+
+impl Sub for &FieldElement {
+    type Output = FieldElement;
+
+    // adding prime first keeps the BigUint subtraction from underflowing
+    fn sub(self, other: &FieldElement) -> FieldElement {
+        self.with_value((&self.value + &self.prime - &other.value) % &self.prime)
+    }
+}
+
+impl Mul for &FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: &FieldElement) -> FieldElement {
+        self.with_value((&self.value * &other.value) % &self.prime)
+    }
+}
+
+impl Neg for &FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        self.with_value((&self.prime - &self.value) % &self.prime)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::field::FieldElement;
+    use num_bigint::{BigInt, BigUint};
+
+    fn element(value: i64) -> FieldElement {
+        FieldElement::new(&BigInt::from(value), &BigInt::from(7))
+    }
+
+    #[test]
+    fn reduction_test() {
+        assert_eq!(element(-1).value(), &BigUint::from(6u8));
+        assert_eq!(element(-14).value(), &BigUint::from(0u8));
+        assert_eq!(element(23).value(), &BigUint::from(2u8));
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        assert_eq!(&element(2) - &element(5), element(4), "2 - 5 = -3 = 4");
+        assert_eq!(&element(4) + &element(5), element(2));
+        assert_eq!(&element(3) * &element(5), element(1));
+        assert_eq!(-&element(0), element(0));
+        assert_eq!(element(3).inverse(), Some(element(5)));
+        assert_eq!(element(0).inverse(), None);
+    }
+}
@@ -0,0 +1,217 @@
+use num_bigint::BigInt;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use super::feldman_vss::{FeldmanResponse, FeldmanVSS};
+use crate::error::SecretSharingError;
+
+#[derive(Debug)]
+pub struct MigrationProof {
+    // fresh per migration, so equal secrets never give equal digests across migrations
+    pub salt: [u8; 32],
+    // hmac-sha256 of the secret recovered from the old and the new dealing under the migration
+    // key. the key is shared with auditors only, an unkeyed digest of a secret from a small
+    // field is brute forced in seconds
+    pub old_digest: [u8; 32],
+    pub new_digest: [u8; 32],
+}
+
+impl MigrationProof {
+    // both dealings hide the same secret: every share of new_dealing verifies against its
+    // commitments under the target parameters, and the secret reconstructed from it digests to
+    // old_digest. the new digest is rederived here, not taken from the proof
+    pub fn is_linked(
+        &self,
+        key: &[u8],
+        target: &FeldmanVSS,
+        new_dealing: &FeldmanResponse,
+    ) -> bool {
+        let verified = new_dealing.shares.iter().all(|share| {
+            FeldmanVSS::verify(&new_dealing.committments, target.group(), share.clone())
+        });
+        if !verified {
+            return false;
+        }
+        match target.reconstruct(&new_dealing.shares) {
+            Ok(secret) => {
+                let digest = secret_digest(key, &self.salt, &secret);
+                digest == self.old_digest && digest == self.new_digest
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+pub struct FieldMigration {
+    // moves a secret from one field/parameter set to another by re-sharing it
+    source: FeldmanVSS,
+    target: FeldmanVSS,
+    verify: bool,
+}
+
+impl FieldMigration {
+    pub fn new(source: FeldmanVSS, target: FeldmanVSS, verify: bool) -> Self {
+        Self {
+            source,
+            target,
+            verify,
+        }
+    }
+
+    // reconstruct the old dealing and re-share the secret under the target parameters. the
+    // proof digests are keyed with key, which auditors need to check it
+    pub fn migrate(
+        &mut self,
+        dealing: &FeldmanResponse,
+        key: &[u8],
+    ) -> Result<(FeldmanResponse, MigrationProof), SecretSharingError> {
+        if self.verify {
+            // check the old shares against the commitments published with the dealing
+            self.source.committments = dealing.committments.clone();
+            for share in dealing.shares.iter() {
+                if !self.source.validate_shares(share.clone()) {
+                    return Err(SecretSharingError::ShareVerificationFailed { index: share.0 });
+                }
+            }
+        }
+
+        let old_secret = self.source.reconstruct(&dealing.shares)?;
+        let new_dealing = self.target.generate_shares(old_secret.clone())?;
+        let new_secret = self.target.reconstruct(&new_dealing.shares)?;
+
+        let salt: [u8; 32] = rand::thread_rng().gen();
+        let proof = MigrationProof {
+            salt,
+            old_digest: secret_digest(key, &salt, &old_secret),
+            new_digest: secret_digest(key, &salt, &new_secret),
+        };
+        Ok((new_dealing, proof))
+    }
+}
+
+// hmac-sha256 over the salt and the length prefixed big-endian bytes of the secret
+fn secret_digest(key: &[u8], salt: &[u8; 32], secret: &BigInt) -> [u8; 32] {
+    let (_, bytes) = secret.to_bytes_be();
+    hmac_sha256(key, &[salt, &(bytes.len() as u64).to_be_bytes(), &bytes])
+}
+
+// rfc 2104 hmac with sha256, message given as consecutive parts
+fn hmac_sha256(key: &[u8], message: &[&[u8]]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > block.len() {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    for part in message {
+        inner.update(part);
+    }
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::feldman_vss::FeldmanVSS;
+    use crate::algorithms::field_migration::{hmac_sha256, FieldMigration};
+    use num_bigint::BigInt;
+
+    const KEY: &[u8] = b"migration audit key";
+
+    // secp256k1 field prime 2^256 - 2^32 - 977
+    fn prime_256() -> BigInt {
+        BigInt::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .unwrap()
+    }
+
+    fn migration() -> FieldMigration {
+        let source = FeldmanVSS::new(2, 3, None).unwrap();
+        let target = FeldmanVSS::new(3, 5, Some(prime_256())).unwrap();
+        FieldMigration::new(source, target, true)
+    }
+
+    #[test]
+    fn migrate_default_prime_to_256_bit_prime() {
+        let secret = BigInt::from(1234);
+        let mut old_vss = FeldmanVSS::new(2, 3, None).unwrap();
+        let old_dealing = old_vss.generate_shares(secret.clone()).unwrap();
+
+        let (new_dealing, proof) = migration().migrate(&old_dealing, KEY).unwrap();
+
+        assert_eq!(
+            new_dealing.shares.len(),
+            5,
+            "New dealing should have 5 shares"
+        );
+        assert_eq!(
+            new_dealing.committments.len(),
+            3,
+            "New dealing should have 3 commitments"
+        );
+        // an auditor with the key and the target parameters, not the migrator's instance
+        let auditor = FeldmanVSS::new(3, 5, Some(prime_256())).unwrap();
+        assert!(
+            proof.is_linked(KEY, &auditor, &new_dealing),
+            "Secret digests should match"
+        );
+        assert!(
+            !proof.is_linked(b"wrong key", &auditor, &new_dealing),
+            "The digest is keyed"
+        );
+
+        let (_, again) = migration().migrate(&old_dealing, KEY).unwrap();
+        assert_ne!(
+            again.old_digest, proof.old_digest,
+            "Every migration should use a fresh salt"
+        );
+    }
+
+    #[test]
+    fn tampered_new_dealing_is_not_linked() {
+        let mut old_vss = FeldmanVSS::new(2, 3, None).unwrap();
+        let old_dealing = old_vss.generate_shares(BigInt::from(1234)).unwrap();
+        let (mut new_dealing, proof) = migration().migrate(&old_dealing, KEY).unwrap();
+        let auditor = FeldmanVSS::new(3, 5, Some(prime_256())).unwrap();
+
+        new_dealing.shares[3].1 += 1;
+        assert!(
+            !proof.is_linked(KEY, &auditor, &new_dealing),
+            "A tampered share should break the link"
+        );
+
+        // a consistent dealing of a different secret verifies but digests differently
+        let mut other = FeldmanVSS::new(3, 5, Some(prime_256())).unwrap();
+        let substituted = other.generate_shares(BigInt::from(4321)).unwrap();
+        assert!(!proof.is_linked(KEY, &auditor, &substituted));
+    }
+
+    #[test]
+    fn migrate_rejects_tampered_dealing() {
+        let mut old_vss = FeldmanVSS::new(2, 3, None).unwrap();
+        let mut old_dealing = old_vss.generate_shares(BigInt::from(1234)).unwrap();
+        old_dealing.shares[0].1 += 1;
+
+        assert!(
+            migration().migrate(&old_dealing, KEY).is_err(),
+            "Tampered share should fail verification"
+        );
+    }
+
+    #[test]
+    fn hmac_sha256_test() {
+        // rfc 4231 test case 2
+        let mac = hmac_sha256(b"Jefe", &[b"what do ya ", b"want for nothing?"]);
+        let hex: String = mac.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(
+            hex,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
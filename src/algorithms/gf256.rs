@@ -0,0 +1,182 @@
+use alloc::{vec, vec::Vec};
+
+use rand::Rng;
+
+use crate::error::SecretSharingError;
+
+// x^8 + x^4 + x^3 + x + 1, the aes reduction polynomial
+const AES_POLYNOMIAL: u16 = 0x11b;
+
+// carry-less multiplication reduced by the aes polynomial
+fn mul(a: u8, b: u8) -> u8 {
+    let (mut a, mut b) = (a as u16, b);
+    let mut product = 0u16;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        a <<= 1;
+        if a & 0x100 != 0 {
+            a ^= AES_POLYNOMIAL;
+        }
+        b >>= 1;
+    }
+    product as u8
+}
+
+// a^254 = a^-1 for every non zero a
+fn inv(a: u8) -> u8 {
+    let mut result = 1;
+    let mut base = a;
+    let mut exponent = 254;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+// share every byte of the secret with its own random polynomial over gf(2^8). each share is
+// (index, bytes) with index in 1..=255 and exactly as many bytes as the secret
+#[cfg(feature = "std")]
+pub fn split_gf256(
+    secret: &[u8],
+    threshold: usize,
+    total_shares: usize,
+) -> Result<Vec<(u8, Vec<u8>)>, SecretSharingError> {
+    split_gf256_with_rng(secret, threshold, total_shares, &mut rand::thread_rng())
+}
+
+// split_gf256 drawing the coefficients from the caller's rng
+pub fn split_gf256_with_rng<R: Rng + ?Sized>(
+    secret: &[u8],
+    threshold: usize,
+    total_shares: usize,
+    rng: &mut R,
+) -> Result<Vec<(u8, Vec<u8>)>, SecretSharingError> {
+    if threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
+    if threshold > total_shares {
+        return Err(SecretSharingError::ThresholdExceedsShares {
+            threshold,
+            total_shares,
+        });
+    }
+    // x = 0 is the secret itself, which leaves 255 indices
+    if total_shares > 255 {
+        return Err(SecretSharingError::TotalSharesAboveLimit {
+            total_shares,
+            max: 255,
+        });
+    }
+
+    let mut shares: Vec<(u8, Vec<u8>)> = (1..=total_shares as u8)
+        .map(|x| (x, Vec::with_capacity(secret.len())))
+        .collect();
+    for byte in secret {
+        let mut coefficients = vec![*byte];
+        coefficients.extend((1..threshold).map(|_| rng.gen::<u8>()));
+        for (x, values) in shares.iter_mut() {
+            // horner evaluation, addition in gf(2^8) is xor
+            let y = coefficients.iter().rev().fold(0, |acc, c| mul(acc, *x) ^ c);
+            values.push(y);
+        }
+    }
+    Ok(shares)
+}
+
+// lagrange interpolation at x = 0 byte by byte, every given share is used
+pub fn combine_gf256(shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, SecretSharingError> {
+    let Some((_, first)) = shares.first() else {
+        return Err(SecretSharingError::InsufficientShares { got: 0, need: 1 });
+    };
+    for (n, (x, values)) in shares.iter().enumerate() {
+        if *x == 0 {
+            return Err(SecretSharingError::InvalidShareIndex { index: 0 });
+        }
+        if shares[..n].iter().any(|(other, _)| other == x) {
+            return Err(SecretSharingError::DuplicateShareIndex { index: *x as usize });
+        }
+        if values.len() != first.len() {
+            return Err(SecretSharingError::InvalidEncoding(
+                "gf256 shares differ in length",
+            ));
+        }
+    }
+
+    // basis_i(0) = prod xj / (xj - xi), subtraction is xor as well
+    let basis: Vec<u8> = shares
+        .iter()
+        .map(|(xi, _)| {
+            shares
+                .iter()
+                .filter(|(xj, _)| xj != xi)
+                .fold(1, |acc, (xj, _)| mul(acc, mul(*xj, inv(xj ^ xi))))
+        })
+        .collect();
+    Ok((0..first.len())
+        .map(|k| {
+            shares
+                .iter()
+                .zip(basis.iter())
+                .fold(0, |acc, ((_, values), b)| acc ^ mul(values[k], *b))
+        })
+        .collect())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::gf256::{combine_gf256, inv, mul, split_gf256};
+    use crate::error::SecretSharingError;
+
+    #[test]
+    fn field_arithmetic_test() {
+        // worked example from fips-197
+        assert_eq!(mul(0x57, 0x83), 0xc1);
+        assert_eq!(mul(0x57, 0x13), 0xfe);
+        for a in 1..=255u8 {
+            assert_eq!(mul(a, inv(a)), 1, "{} times its inverse should be 1", a);
+        }
+    }
+
+    #[test]
+    fn gf256_round_trip_test() {
+        let key: Vec<u8> = (0..16).map(|i| i * 17).collect();
+        let shares = split_gf256(&key, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+        for (_, values) in shares.iter() {
+            assert_eq!(
+                values.len(),
+                key.len(),
+                "Each share should be as long as the secret"
+            );
+        }
+        assert_eq!(combine_gf256(&shares[..3]).unwrap(), key);
+        assert_eq!(combine_gf256(&shares[2..]).unwrap(), key);
+        assert_eq!(
+            combine_gf256(&[shares[0].clone(), shares[4].clone(), shares[2].clone()]).unwrap(),
+            key,
+            "Any three shares in any order should recover the key"
+        );
+    }
+
+    #[test]
+    fn gf256_validation_test() {
+        assert_eq!(
+            split_gf256(b"key", 2, 256),
+            Err(SecretSharingError::TotalSharesAboveLimit {
+                total_shares: 256,
+                max: 255
+            })
+        );
+        let shares = split_gf256(b"key", 2, 3).unwrap();
+        assert_eq!(
+            combine_gf256(&[shares[1].clone(), shares[1].clone()]),
+            Err(SecretSharingError::DuplicateShareIndex { index: 2 })
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use alloc::{vec, vec::Vec};
+
+use rand::Rng;
+
+use crate::error::SecretSharingError;
+
+// shamir over gf(2^degree) with a caller supplied reduction polynomial. gf256 is the degree 8
+// case with the aes polynomial, larger degrees share a multi-byte symbol per polynomial
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gf2nShamir {
+    degree: u32,
+    // field polynomial without its x^degree term, which is implied so degree 128 still fits a
+    // u128, e.g. 0x2b for x^16 + x^5 + x^3 + x + 1
+    reduction: u128,
+    threshold: usize,
+    total_shares: usize,
+}
+
+impl Gf2nShamir {
+    // degree has to be a multiple of 8 so field elements are whole bytes, and at most 128 so an
+    // element fits in a u128
+    pub fn new(
+        degree: u32,
+        reduction: u128,
+        threshold: usize,
+        total_shares: usize,
+    ) -> Result<Self, SecretSharingError> {
+        if degree == 0 || degree > 128 || !degree.is_multiple_of(8) {
+            return Err(SecretSharingError::UnsupportedFieldDegree { degree });
+        }
+        if threshold == 0 {
+            return Err(SecretSharingError::ZeroThreshold);
+        }
+        if threshold > total_shares {
+            return Err(SecretSharingError::ThresholdExceedsShares {
+                threshold,
+                total_shares,
+            });
+        }
+        let field = Gf2nShamir {
+            degree,
+            reduction,
+            threshold,
+            total_shares,
+        };
+        if reduction > field.mask() || !field.is_irreducible() {
+            return Err(SecretSharingError::ReducibleFieldPolynomial);
+        }
+        // x = 0 is the secret itself, which leaves 2^degree - 1 indices
+        let max = usize::try_from(field.mask()).unwrap_or(usize::MAX);
+        if total_shares > max {
+            return Err(SecretSharingError::TotalSharesAboveLimit { total_shares, max });
+        }
+        Ok(field)
+    }
+
+    // bytes per field element, secrets are split into symbols of this size
+    pub fn symbol_size(&self) -> usize {
+        (self.degree / 8) as usize
+    }
+
+    // 2^degree - 1, every field element is at most this
+    fn mask(&self) -> u128 {
+        u128::MAX >> (128 - self.degree)
+    }
+
+    // carry-less multiplication reduced by the field polynomial. the top bit is tested before
+    // shifting, so a shifted element never needs bit degree and degree 128 fits
+    fn mul(&self, a: u128, b: u128) -> u128 {
+        let (mut a, mut b) = (a, b);
+        let top = 1u128 << (self.degree - 1);
+        let mut product = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                product ^= a;
+            }
+            let carry = a & top != 0;
+            a = (a << 1) & self.mask();
+            if carry {
+                a ^= self.reduction;
+            }
+            b >>= 1;
+        }
+        product
+    }
+
+    // a^(2^degree - 2) = a^-1 for every non zero a
+    fn inv(&self, a: u128) -> u128 {
+        let mut result = 1;
+        let mut base = a;
+        let mut exponent = self.mask() - 1;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    // ben-or: f of degree n is irreducible iff gcd(x^(2^i) - x, f) = 1 for i in 1..=n/2.
+    // gcd(g, f) is taken as gcd(g, f mod g) since f itself may not fit in a u128
+    fn is_irreducible(&self) -> bool {
+        let mut power = 2;
+        for _ in 0..self.degree / 2 {
+            power = self.mul(power, power);
+            let g = power ^ 2;
+            if g == 0 || poly_gcd(g, self.field_polynomial_mod(g)) != 1 {
+                return false;
+            }
+        }
+        true
+    }
+
+    // (x^degree + reduction) mod g for a non zero g of lower degree
+    fn field_polynomial_mod(&self, g: u128) -> u128 {
+        let mut x_power = poly_mod(1, g);
+        for _ in 0..self.degree {
+            x_power = poly_mod(x_power << 1, g);
+        }
+        x_power ^ poly_mod(self.reduction, g)
+    }
+
+    // share every symbol of the secret with its own random polynomial. each share is
+    // (index, bytes) with index in 1..=total_shares and exactly as many bytes as the secret
+    #[cfg(feature = "std")]
+    pub fn split(&self, secret: &[u8]) -> Result<Vec<(usize, Vec<u8>)>, SecretSharingError> {
+        self.split_with_rng(secret, &mut rand::thread_rng())
+    }
+
+    // split drawing the coefficients from the caller's rng
+    pub fn split_with_rng<R: Rng + ?Sized>(
+        &self,
+        secret: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<(usize, Vec<u8>)>, SecretSharingError> {
+        let size = self.symbol_size();
+        if !secret.len().is_multiple_of(size) {
+            return Err(SecretSharingError::InvalidEncoding(
+                "secret length is not a multiple of the field element size",
+            ));
+        }
+
+        let mask = self.mask();
+        let mut shares: Vec<(usize, Vec<u8>)> = (1..=self.total_shares)
+            .map(|x| (x, Vec::with_capacity(secret.len())))
+            .collect();
+        for symbol in secret.chunks(size) {
+            let mut coefficients = vec![to_element(symbol)];
+            coefficients.extend((1..self.threshold).map(|_| rng.gen::<u128>() & mask));
+            for (x, values) in shares.iter_mut() {
+                // horner evaluation, addition in gf(2^n) is xor
+                let y = coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, c| self.mul(acc, *x as u128) ^ c);
+                values.extend_from_slice(&from_element(y, size));
+            }
+        }
+        Ok(shares)
+    }
+
+    // lagrange interpolation at x = 0 symbol by symbol, every given share is used
+    pub fn combine(&self, shares: &[(usize, Vec<u8>)]) -> Result<Vec<u8>, SecretSharingError> {
+        let Some((_, first)) = shares.first() else {
+            return Err(SecretSharingError::InsufficientShares { got: 0, need: 1 });
+        };
+        let size = self.symbol_size();
+        for (n, (x, values)) in shares.iter().enumerate() {
+            if *x == 0 || *x as u128 > self.mask() {
+                return Err(SecretSharingError::InvalidShareIndex { index: *x });
+            }
+            if shares[..n].iter().any(|(other, _)| other == x) {
+                return Err(SecretSharingError::DuplicateShareIndex { index: *x });
+            }
+            if values.len() != first.len() || !values.len().is_multiple_of(size) {
+                return Err(SecretSharingError::InvalidEncoding(
+                    "gf2n shares differ in length",
+                ));
+            }
+        }
+
+        // basis_i(0) = prod xj / (xj - xi), subtraction is xor as well
+        let basis: Vec<u128> = shares
+            .iter()
+            .map(|(xi, _)| {
+                shares
+                    .iter()
+                    .filter(|(xj, _)| xj != xi)
+                    .fold(1, |acc, (xj, _)| {
+                        let (xi, xj) = (*xi as u128, *xj as u128);
+                        self.mul(acc, self.mul(xj, self.inv(xj ^ xi)))
+                    })
+            })
+            .collect();
+        let mut secret = Vec::with_capacity(first.len());
+        for k in (0..first.len()).step_by(size) {
+            let y = shares
+                .iter()
+                .zip(basis.iter())
+                .fold(0, |acc, ((_, values), b)| {
+                    acc ^ self.mul(to_element(&values[k..k + size]), *b)
+                });
+            secret.extend_from_slice(&from_element(y, size));
+        }
+        Ok(secret)
+    }
+}
+
+// big-endian symbol bytes as a field element
+fn to_element(bytes: &[u8]) -> u128 {
+    bytes.iter().fold(0, |acc, b| (acc << 8) | *b as u128)
+}
+
+// field element as size big-endian bytes
+fn from_element(value: u128, size: usize) -> Vec<u8> {
+    value.to_be_bytes()[16 - size..].to_vec()
+}
+
+// gcd of two polynomials over gf(2), bit i is the coefficient of x^i
+fn poly_gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, poly_mod(a, b));
+    }
+    a
+}
+
+// remainder of a divided by a non zero b over gf(2)
+fn poly_mod(mut a: u128, b: u128) -> u128 {
+    let degree_b = 127 - b.leading_zeros();
+    while a != 0 && 127 - a.leading_zeros() >= degree_b {
+        a ^= b << (127 - a.leading_zeros() - degree_b);
+    }
+    a
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::gf256::split_gf256;
+    use crate::algorithms::gf2n::Gf2nShamir;
+    use crate::error::SecretSharingError;
+
+    // x^16 + x^5 + x^3 + x + 1, the x^16 term is implied
+    const GF2_16_POLYNOMIAL: u128 = 0x2b;
+    // x^128 + x^7 + x^2 + x + 1, the gcm polynomial
+    const GF2_128_POLYNOMIAL: u128 = 0x87;
+
+    #[test]
+    fn gf2_8_round_trip_test() {
+        let field = Gf2nShamir::new(8, 0x1b, 3, 5).unwrap();
+        let key: Vec<u8> = (0..16).map(|i| i * 17).collect();
+        let shares = field.split(&key).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert_eq!(field.combine(&shares[..3]).unwrap(), key);
+        assert_eq!(
+            field
+                .combine(&[shares[4].clone(), shares[0].clone(), shares[2].clone()])
+                .unwrap(),
+            key,
+            "Any three shares in any order should recover the key"
+        );
+
+        // the aes polynomial makes this the same field as gf256
+        let shares = split_gf256(&key, 2, 3).unwrap();
+        let shares: Vec<(usize, Vec<u8>)> = shares
+            .into_iter()
+            .map(|(x, values)| (x as usize, values))
+            .collect();
+        assert_eq!(
+            Gf2nShamir::new(8, 0x1b, 2, 3)
+                .unwrap()
+                .combine(&shares[1..])
+                .unwrap(),
+            key,
+            "Degree 8 with the aes polynomial should combine gf256 shares"
+        );
+    }
+
+    #[test]
+    fn gf2_16_round_trip_test() {
+        let field = Gf2nShamir::new(16, GF2_16_POLYNOMIAL, 4, 7).unwrap();
+        assert_eq!(field.symbol_size(), 2);
+        let key = b"sixteen bit symbols!".to_vec();
+        let shares = field.split(&key).unwrap();
+        for (_, values) in shares.iter() {
+            assert_eq!(
+                values.len(),
+                key.len(),
+                "Each share should be as long as the secret"
+            );
+        }
+        assert_eq!(field.combine(&shares[3..]).unwrap(), key);
+        assert_ne!(
+            field.combine(&shares[..3]).unwrap(),
+            key,
+            "Fewer than threshold shares should not recover the key"
+        );
+        assert_eq!(
+            field.split(b"odd"),
+            Err(SecretSharingError::InvalidEncoding(
+                "secret length is not a multiple of the field element size"
+            ))
+        );
+    }
+
+    #[test]
+    fn gf2_128_round_trip_test() {
+        let field = Gf2nShamir::new(128, GF2_128_POLYNOMIAL, 3, 5).unwrap();
+        assert_eq!(field.symbol_size(), 16);
+        let key: Vec<u8> = (0..32).map(|i| 255 - i * 7).collect();
+        let shares = field.split(&key).unwrap();
+        assert_eq!(field.combine(&shares[2..]).unwrap(), key);
+        assert_eq!(
+            field
+                .combine(&[shares[4].clone(), shares[0].clone(), shares[3].clone()])
+                .unwrap(),
+            key,
+            "Any three shares should recover a key over gf(2^128)"
+        );
+
+        // x^127 * x = x^128 = x^7 + x^2 + x + 1
+        assert_eq!(field.mul(1 << 127, 2), GF2_128_POLYNOMIAL);
+        let a = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210;
+        assert_eq!(field.mul(a, field.inv(a)), 1);
+    }
+
+    #[test]
+    fn gf2n_validation_test() {
+        assert_eq!(
+            Gf2nShamir::new(12, 0x1009, 2, 3),
+            Err(SecretSharingError::UnsupportedFieldDegree { degree: 12 })
+        );
+        // x^8 + 1 = (x + 1)^8
+        assert_eq!(
+            Gf2nShamir::new(8, 0x01, 2, 3),
+            Err(SecretSharingError::ReducibleFieldPolynomial)
+        );
+        assert_eq!(
+            Gf2nShamir::new(8, 0x11b, 2, 3),
+            Err(SecretSharingError::ReducibleFieldPolynomial),
+            "The x^degree term is implied, not passed"
+        );
+        // x^128 + x^7 + x^2 + x is divisible by x
+        assert_eq!(
+            Gf2nShamir::new(128, 0x86, 2, 3),
+            Err(SecretSharingError::ReducibleFieldPolynomial)
+        );
+        assert_eq!(
+            Gf2nShamir::new(136, GF2_128_POLYNOMIAL, 2, 3),
+            Err(SecretSharingError::UnsupportedFieldDegree { degree: 136 })
+        );
+        assert_eq!(
+            Gf2nShamir::new(8, 0x1b, 2, 256),
+            Err(SecretSharingError::TotalSharesAboveLimit {
+                total_shares: 256,
+                max: 255
+            })
+        );
+        let field = Gf2nShamir::new(16, GF2_16_POLYNOMIAL, 2, 3).unwrap();
+        let shares = field.split(b"ab").unwrap();
+        assert_eq!(
+            field.combine(&[shares[1].clone(), shares[1].clone()]),
+            Err(SecretSharingError::DuplicateShareIndex { index: 2 })
+        );
+    }
+}
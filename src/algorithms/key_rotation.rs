@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
+#[cfg(feature = "zeroize-on-drop")]
+use zeroize::Zeroizing;
+
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+// wraps a key under a key encryption key (kek)
+pub trait KeyEncryptor {
+    fn encrypt(&self, kek: &[u8], plaintext: &[u8]) -> Vec<u8>;
+    fn decrypt(&self, kek: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, SecretSharingError>;
+}
+
+impl ShamirSecretSharing {
+    // reconstruct a key shared with split_bytes and wrap it under new_key in one step. with
+    // zeroize-on-drop the assembled plaintext key is zeroed when dropped, but the per block
+    // bigints and byte buffers combine_bytes goes through on the way are freed without being
+    // wiped
+    pub fn reconstruct_and_rewrap(
+        &self,
+        shares: &[(usize, Vec<BigInt>)],
+        new_key: &[u8],
+        encryptor: &dyn KeyEncryptor,
+    ) -> Result<Vec<u8>, SecretSharingError> {
+        let plaintext = self.combine_bytes(shares)?;
+        #[cfg(feature = "zeroize-on-drop")]
+        let plaintext = Zeroizing::new(plaintext);
+        Ok(encryptor.encrypt(new_key, &plaintext))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::key_rotation::KeyEncryptor;
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::error::SecretSharingError;
+
+    // xor with the repeating kek, only good enough for tests
+    struct XorKeyEncryptor;
+
+    impl KeyEncryptor for XorKeyEncryptor {
+        fn encrypt(&self, kek: &[u8], plaintext: &[u8]) -> Vec<u8> {
+            plaintext
+                .iter()
+                .zip(kek.iter().cycle())
+                .map(|(b, k)| b ^ k)
+                .collect()
+        }
+
+        fn decrypt(&self, kek: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, SecretSharingError> {
+            Ok(self.encrypt(kek, ciphertext))
+        }
+    }
+
+    #[test]
+    fn reconstruct_and_rewrap_test() {
+        let data_key: Vec<u8> = (0..32).collect();
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.split_bytes(&data_key).unwrap();
+
+        let new_kek = b"rotated key encryption key";
+        let wrapped = shamir
+            .reconstruct_and_rewrap(&shares[1..4], new_kek, &XorKeyEncryptor)
+            .unwrap();
+        assert_ne!(wrapped, data_key, "Wrapped key should not be the plaintext");
+        assert_eq!(
+            XorKeyEncryptor.decrypt(new_kek, &wrapped).unwrap(),
+            data_key,
+            "Unwrapping with the new kek should give the original key"
+        );
+    }
+
+    #[test]
+    fn reconstruct_and_rewrap_insufficient_shares_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.split_bytes(b"data key").unwrap();
+        assert!(
+            shamir
+                .reconstruct_and_rewrap(&shares[..2], b"kek", &XorKeyEncryptor)
+                .is_err(),
+            "Too few shares should fail before anything is wrapped"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use alloc::{string::String, string::ToString, vec, vec::Vec};
+
+use num_bigint::{BigInt, Sign};
+use sha2::{Digest, Sha256};
+
+use crate::error::SecretSharingError;
+
+// the BIP-39 english wordlist, 2048 words with unique 4 letter prefixes, 11 bits each
+const WORDLIST: &str = include_str!("mnemonic_wordlist.txt");
+const BITS_PER_WORD: usize = 11;
+const WORD_COUNT: usize = 1 << BITS_PER_WORD;
+
+// split once at compile time, a list without exactly WORD_COUNT newline terminated words fails
+// the build
+static WORDS: [&str; WORD_COUNT] = split_wordlist(WORDLIST);
+
+const fn split_wordlist(list: &'static str) -> [&'static str; WORD_COUNT] {
+    let bytes = list.as_bytes();
+    let mut words = [""; WORD_COUNT];
+    let (mut start, mut end, mut count) = (0, 0, 0);
+    while end < bytes.len() {
+        if bytes[end] == b'\n' {
+            assert!(count < WORD_COUNT, "mnemonic wordlist has too many words");
+            let (line, _) = bytes.split_at(end);
+            let (_, word) = line.split_at(start);
+            words[count] = match core::str::from_utf8(word) {
+                Ok(word) => word,
+                Err(_) => panic!("mnemonic wordlist is not utf-8"),
+            };
+            count += 1;
+            start = end + 1;
+        }
+        end += 1;
+    }
+    assert!(count == WORD_COUNT, "mnemonic wordlist has too few words");
+    words
+}
+
+// 11 bit checksum over the index and the value bytes
+fn checksum(index: usize, value: &[u8]) -> usize {
+    let mut hasher = Sha256::new();
+    hasher.update((index as u64).to_be_bytes());
+    hasher.update(value);
+    let digest = hasher.finalize();
+    (((digest[0] as usize) << 8) | digest[1] as usize) >> (16 - BITS_PER_WORD)
+}
+
+// share as words: [index, value byte length, value bits in 11 bit groups..., checksum]
+pub fn share_to_mnemonic(share: &(usize, BigInt)) -> Result<Vec<String>, SecretSharingError> {
+    let (sign, value) = share.1.to_bytes_be();
+    if sign == Sign::Minus {
+        return Err(SecretSharingError::InvalidEncoding("negative share value"));
+    }
+    let value: &[u8] = if sign == Sign::NoSign { &[] } else { &value };
+    if share.0 >= WORD_COUNT {
+        return Err(SecretSharingError::InvalidEncoding(
+            "share index too large for a mnemonic",
+        ));
+    }
+    if value.len() >= WORD_COUNT {
+        return Err(SecretSharingError::InvalidEncoding(
+            "share value too large for a mnemonic",
+        ));
+    }
+
+    let mut groups = vec![share.0, value.len()];
+    let bits: Vec<bool> = value
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1 == 1))
+        .collect();
+    // last group is zero padded to a full word
+    for chunk in bits.chunks(BITS_PER_WORD) {
+        let group = (0..BITS_PER_WORD).fold(0, |acc, k| {
+            (acc << 1) | usize::from(chunk.get(k).copied().unwrap_or(false))
+        });
+        groups.push(group);
+    }
+    groups.push(checksum(share.0, value));
+
+    Ok(groups.into_iter().map(|g| WORDS[g].to_string()).collect())
+}
+
+// inverse of share_to_mnemonic, unknown words and checksum mismatches are errors
+pub fn share_from_mnemonic(words: &[&str]) -> Result<(usize, BigInt), SecretSharingError> {
+    let groups = words
+        .iter()
+        .map(|word| {
+            WORDS
+                .iter()
+                .position(|w| w.eq_ignore_ascii_case(word.trim()))
+                .ok_or(SecretSharingError::InvalidEncoding("unknown mnemonic word"))
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+    if groups.len() < 3 {
+        return Err(SecretSharingError::InvalidEncoding("mnemonic too short"));
+    }
+
+    let index = groups[0];
+    let length = groups[1];
+    let data = &groups[2..groups.len() - 1];
+    if data.len() != (length * 8).div_ceil(BITS_PER_WORD) {
+        return Err(SecretSharingError::InvalidEncoding(
+            "mnemonic length does not match its header",
+        ));
+    }
+    let bits: Vec<bool> = data
+        .iter()
+        .flat_map(|g| (0..BITS_PER_WORD).rev().map(move |bit| (g >> bit) & 1 == 1))
+        .collect();
+    let value: Vec<u8> = bits[..length * 8]
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0u8, |acc, b| (acc << 1) | u8::from(*b)))
+        .collect();
+
+    if groups[groups.len() - 1] != checksum(index, &value) {
+        return Err(SecretSharingError::InvalidEncoding(
+            "mnemonic checksum mismatch",
+        ));
+    }
+    Ok((index, BigInt::from_bytes_be(Sign::Plus, &value)))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::mnemonic::{share_from_mnemonic, share_to_mnemonic, WORDS};
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    fn as_strs(words: &[String]) -> Vec<&str> {
+        words.iter().map(|w| w.as_str()).collect()
+    }
+
+    #[test]
+    fn mnemonic_round_trip_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let decoded: Vec<(usize, BigInt)> = shares
+            .iter()
+            .map(|share| {
+                let words = share_to_mnemonic(share).unwrap();
+                share_from_mnemonic(&as_strs(&words)).unwrap()
+            })
+            .collect();
+        assert_eq!(decoded, shares, "Shares should round trip through words");
+        assert_eq!(
+            shamir.reconstruct_tuples(&decoded[..3]).unwrap(),
+            BigInt::from(1234)
+        );
+
+        let zero = (7, BigInt::from(0));
+        let words = share_to_mnemonic(&zero).unwrap();
+        assert_eq!(share_from_mnemonic(&as_strs(&words)).unwrap(), zero);
+    }
+
+    #[test]
+    fn mnemonic_corrupted_word_test() {
+        let share = (2, BigInt::from(0x1234_5678_9abc_u64));
+        let words = share_to_mnemonic(&share).unwrap();
+
+        let mut swapped = as_strs(&words);
+        swapped[3] = if swapped[3] == "abandon" {
+            "ability"
+        } else {
+            "abandon"
+        };
+        assert_eq!(
+            share_from_mnemonic(&swapped),
+            Err(SecretSharingError::InvalidEncoding(
+                "mnemonic checksum mismatch"
+            )),
+            "A wrong but valid word should fail the checksum"
+        );
+
+        let mut unknown = as_strs(&words);
+        unknown[2] = "notaword";
+        assert_eq!(
+            share_from_mnemonic(&unknown),
+            Err(SecretSharingError::InvalidEncoding("unknown mnemonic word")),
+            "A word outside the list should be rejected"
+        );
+    }
+
+    #[test]
+    fn bip39_wordlist_test() {
+        assert_eq!(WORDS[0], "abandon");
+        assert_eq!(WORDS[1234], "olympic");
+        assert_eq!(WORDS[2047], "zoo");
+        assert!(
+            WORDS.windows(2).all(|pair| pair[0] < pair[1]),
+            "The list should be sorted without repeats"
+        );
+        let prefixes: std::collections::HashSet<&str> = WORDS
+            .iter()
+            .map(|word| &word[..word.len().min(4)])
+            .collect();
+        assert_eq!(
+            prefixes.len(),
+            2048,
+            "Every 4 letter prefix should be unique"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use alloc::vec::Vec;
+
+use num_bigint::{BigInt, RandBigInt};
+use rand::Rng;
+
+use super::shamir_secret_sharing::{
+    check_congruent_indices, check_shares_for, default_prime, mod_inverse, ShamirSecretSharing,
+};
+use crate::error::SecretSharingError;
+
+// packed shamir: k secrets sit at x = 0, -1, ..., -(k-1) of one polynomial of degree
+// threshold + k - 1, so threshold shares still reveal nothing and threshold + k shares recover
+// every secret. shares are over the default prime 2^31 - 1
+#[cfg(feature = "std")]
+pub fn split_packed(
+    secrets: &[BigInt],
+    threshold: usize,
+    total_shares: usize,
+) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+    split_packed_with_rng(secrets, threshold, total_shares, &mut rand::thread_rng())
+}
+
+// split_packed drawing the random shares from the caller's rng
+pub fn split_packed_with_rng<R: Rng + ?Sized>(
+    secrets: &[BigInt],
+    threshold: usize,
+    total_shares: usize,
+    rng: &mut R,
+) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+    if threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
+    if secrets.is_empty() {
+        return Err(SecretSharingError::IncompatibleDealing(
+            "no secrets to pack",
+        ));
+    }
+    // validates threshold + k <= total_shares, the limits and total_shares < prime
+    let shamir = ShamirSecretSharing::new(threshold + secrets.len(), total_shares, None)?;
+    let prime = &shamir.prime;
+    // share indices 1..=n and the k secret points all have to be distinct field elements
+    if BigInt::from(total_shares + secrets.len()) >= *prime {
+        return Err(SecretSharingError::SharesExceedPrime {
+            total_shares: total_shares + secrets.len(),
+            prime: prime.clone(),
+        });
+    }
+    for secret in secrets {
+        shamir.check_secret(secret)?;
+    }
+
+    // the polynomial is fixed by the secrets plus threshold uniformly random shares
+    let mut points = secret_points(secrets.len(), prime);
+    for (point, secret) in points.iter_mut().zip(secrets) {
+        point.1 = secret.clone();
+    }
+    let random: Vec<(usize, BigInt)> = (1..=threshold)
+        .map(|i| (i, rng.gen_bigint_range(&BigInt::from(0), prime)))
+        .collect();
+    points.extend(random.iter().map(|(i, y)| (BigInt::from(*i), y.clone())));
+
+    let mut shares = random;
+    for i in threshold + 1..=total_shares {
+        shares.push((i, evaluate_at(&points, &BigInt::from(i), prime)));
+    }
+    Ok(shares)
+}
+
+// recover all secret_count secrets from at least threshold + secret_count shares of split_packed
+pub fn combine_packed(
+    shares: &[(usize, BigInt)],
+    threshold: usize,
+    secret_count: usize,
+) -> Result<Vec<BigInt>, SecretSharingError> {
+    check_shares_for(shares, threshold + secret_count)?;
+    let prime = default_prime();
+    check_congruent_indices(shares, &prime)?;
+    let targets = secret_points(secret_count, &prime);
+    for (index, _) in shares {
+        let x = BigInt::from(*index) % &prime;
+        if targets.iter().any(|(t, _)| *t == x) {
+            return Err(SecretSharingError::InvalidShareIndex { index: *index });
+        }
+    }
+    let points: Vec<(BigInt, BigInt)> = shares
+        .iter()
+        .map(|(i, y)| (BigInt::from(*i), ((y % &prime) + &prime) % &prime))
+        .collect();
+    Ok(targets
+        .iter()
+        .map(|(x, _)| evaluate_at(&points, x, &prime))
+        .collect())
+}
+
+// x = 0, -1, ..., -(count-1) mod prime with a zero value to fill in
+fn secret_points(count: usize, prime: &BigInt) -> Vec<(BigInt, BigInt)> {
+    (0..count)
+        .map(|j| ((prime - j) % prime, BigInt::from(0)))
+        .collect()
+}
+
+// value at x of the polynomial through points, all coordinates mod prime
+fn evaluate_at(points: &[(BigInt, BigInt)], x: &BigInt, prime: &BigInt) -> BigInt {
+    let mut value = BigInt::from(0);
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let mut num = BigInt::from(1);
+        let mut denom = BigInt::from(1);
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i != j {
+                num = (num * (x - xj)) % prime;
+                denom = (denom * (xi - xj)) % prime;
+            }
+        }
+        let inverse = mod_inverse(&denom, prime).expect("points are distinct mod prime");
+        value = (value + num * inverse % prime * yi) % prime;
+    }
+    ((value % prime) + prime) % prime
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::packed::{combine_packed, split_packed};
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    fn secrets() -> Vec<BigInt> {
+        [11, 22, 33].iter().map(|s| BigInt::from(*s)).collect()
+    }
+
+    #[test]
+    fn packed_round_trip_test() {
+        let shares = split_packed(&secrets(), 2, 7).unwrap();
+        assert_eq!(shares.len(), 7);
+        assert_eq!(
+            combine_packed(&shares[..5], 2, 3).unwrap(),
+            secrets(),
+            "threshold + 3 shares should recover every secret"
+        );
+        assert_eq!(
+            combine_packed(&shares[2..], 2, 3).unwrap(),
+            secrets(),
+            "Any five shares should work"
+        );
+        assert_eq!(
+            combine_packed(&shares[..4], 2, 3),
+            Err(SecretSharingError::InsufficientShares { got: 4, need: 5 })
+        );
+    }
+
+    #[test]
+    fn packed_validation_test() {
+        assert_eq!(
+            split_packed(&secrets(), 3, 5),
+            Err(SecretSharingError::ThresholdExceedsShares {
+                threshold: 6,
+                total_shares: 5
+            }),
+            "total_shares has to cover threshold plus every secret"
+        );
+        assert_eq!(
+            split_packed(&[], 2, 5),
+            Err(SecretSharingError::IncompatibleDealing(
+                "no secrets to pack"
+            ))
+        );
+        assert_eq!(
+            split_packed(&[BigInt::from(1), BigInt::from(-1)], 2, 5),
+            Err(SecretSharingError::NegativeSecret)
+        );
+    }
+}
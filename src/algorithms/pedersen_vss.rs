@@ -0,0 +1,227 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use rayon::prelude::*;
+
+use num_bigint::{BigInt, RandBigInt};
+use rand::Rng;
+#[cfg(feature = "std")]
+use rayon::iter::IntoParallelIterator;
+
+use super::feldman_vss::{committment_product, CommitmentGroup};
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+// domain separation tag h is derived from, verifiers rederive h with the same tag
+const BLINDING_GENERATOR_DOMAIN: &[u8] = b"secret-sharing pedersen blinding generator";
+
+#[derive(Debug)]
+pub struct PedersenResponse {
+    pub shares: Vec<(usize, BigInt)>,
+    // r(i) for every share, needed next to the share to verify it
+    pub blinding_shares: Vec<(usize, BigInt)>,
+    pub committments: Vec<BigInt>,
+}
+
+pub struct PedersenVSS {
+    // pedersenvss is sss with perfectly hiding committments Cj = g^aj * h^bj
+    pub committments: Vec<BigInt>,
+    // order q subgroup of Z_P^* shared with feldman, its generator is g
+    group: CommitmentGroup,
+    // second generator, log_g(h) must be unknown to the dealer
+    blinding_generator: BigInt,
+    shamir: ShamirSecretSharing,
+    // shares the random blinding polynomial r(x)
+    blinding: ShamirSecretSharing,
+}
+
+impl PedersenVSS {
+    pub fn new(
+        threshold: usize,
+        total_shares: usize,
+        prime: Option<BigInt>,
+    ) -> Result<Self, SecretSharingError> {
+        // shamir objects for f(x) and r(x) over the same field
+        let shamir = ShamirSecretSharing::new(threshold, total_shares, prime)?;
+        let blinding =
+            ShamirSecretSharing::new(threshold, total_shares, Some(shamir.prime.clone()))?;
+
+        // shares are reduced mod q, so both generators have to be of order q. h is hashed into
+        // the group rather than picked, so nobody knows log_g(h)
+        let group = CommitmentGroup::for_prime(&shamir.prime);
+        let blinding_generator = group.hash_to_group(BLINDING_GENERATOR_DOMAIN);
+        for generator in [&group.generator, &blinding_generator] {
+            if !group.is_valid_generator(generator) {
+                return Err(SecretSharingError::InvalidGenerator {
+                    generator: generator.clone(),
+                });
+            }
+        }
+        if blinding_generator == group.generator {
+            return Err(SecretSharingError::InvalidGenerator {
+                generator: blinding_generator,
+            });
+        }
+
+        Ok(Self {
+            group,
+            blinding_generator,
+            committments: Vec::new(),
+            shamir,
+            blinding,
+        })
+    }
+
+    pub fn group(&self) -> &CommitmentGroup {
+        &self.group
+    }
+
+    pub fn blinding_generator(&self) -> &BigInt {
+        &self.blinding_generator
+    }
+
+    // generate Cj = g^aj * h^bj committments
+    fn generate_committments(&mut self) {
+        let coefficients = &self.shamir.polynomial.coefficients;
+        let blinding_coefficients = &self.blinding.polynomial.coefficients;
+        let group = &self.group;
+        #[cfg(feature = "std")]
+        let indices = (0..coefficients.len()).into_par_iter();
+        #[cfg(not(feature = "std"))]
+        let indices = 0..coefficients.len();
+        self.committments = indices
+            .map(|i| {
+                let g = group.commit(&coefficients[i]);
+                let h = self
+                    .blinding_generator
+                    .modpow(&blinding_coefficients[i], &group.modulus);
+                (g * h) % &group.modulus
+            })
+            .collect();
+    }
+
+    // share the secret with f(x) and a random r(x) in lockstep
+    #[cfg(feature = "std")]
+    pub fn generate_shares(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<PedersenResponse, SecretSharingError> {
+        self.generate_shares_with_rng(secret, &mut rand::thread_rng())
+    }
+
+    // generate_shares drawing both polynomials from the caller's rng
+    pub fn generate_shares_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        secret: BigInt,
+        rng: &mut R,
+    ) -> Result<PedersenResponse, SecretSharingError> {
+        let shares = self.shamir.generate_shares_with_rng(secret, rng)?;
+        let blinding_secret = rng.gen_bigint_range(&BigInt::from(0), &self.shamir.prime);
+        let blinding_shares = self
+            .blinding
+            .generate_shares_with_rng(blinding_secret, rng)?;
+        self.generate_committments();
+        Ok(PedersenResponse {
+            shares,
+            blinding_shares,
+            committments: self.committments.clone(),
+        })
+    }
+
+    // check g^f(i) * h^r(i) == prod Cj^(i^j)
+    pub fn validate_shares(&self, share: (usize, BigInt), blinding_share: BigInt) -> bool {
+        let group = &self.group;
+        let blinding_share = ((blinding_share % &group.order) + &group.order) % &group.order;
+        let lhs = (group.commit(&share.1)
+            * self
+                .blinding_generator
+                .modpow(&blinding_share, &group.modulus))
+            % &group.modulus;
+        committment_product(&group.modulus, &group.order, &self.committments, share.0)
+            .is_some_and(|rhs| lhs == rhs)
+    }
+
+    pub fn reconstruct(&self, shares: &[(usize, BigInt)]) -> Result<BigInt, SecretSharingError> {
+        self.shamir.reconstruct_tuples(shares)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::feldman_vss::CommitmentGroup;
+    use crate::algorithms::pedersen_vss::{PedersenVSS, BLINDING_GENERATOR_DOMAIN};
+    use crate::algorithms::shamir_secret_sharing::default_prime;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_generators() {
+        let vss = PedersenVSS::new(3, 5, None).unwrap();
+        let group = vss.group();
+        // 2 and 3 generate all of Z_p^* for the default prime, not a prime order subgroup
+        assert_eq!(group.modulus, default_prime() * 46 + 1);
+        assert!(group.is_valid_generator(&group.generator));
+        assert!(
+            group.is_valid_generator(vss.blinding_generator()),
+            "h should lie in the order q subgroup"
+        );
+        assert_ne!(vss.blinding_generator(), &group.generator);
+        assert_eq!(
+            &CommitmentGroup::for_prime(&default_prime()).hash_to_group(BLINDING_GENERATOR_DOMAIN),
+            vss.blinding_generator(),
+            "Anyone should be able to rederive h from the tag"
+        );
+        assert_ne!(
+            group.hash_to_group(b"another tag"),
+            *vss.blinding_generator()
+        );
+
+        let large = PedersenVSS::new(3, 5, Some((BigInt::from(1) << 127) - 1)).unwrap();
+        assert!(large.group().is_valid_generator(large.blinding_generator()));
+    }
+
+    #[test]
+    fn test_validate_shares_valid() {
+        let mut vss = PedersenVSS::new(3, 12, None).unwrap();
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            response.committments.len(),
+            3,
+            "Number of commitments should match threshold"
+        );
+        for (share, (_, blinding)) in response.shares.iter().zip(response.blinding_shares) {
+            assert!(
+                vss.validate_shares(share.clone(), blinding),
+                "Honest share should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_shares_invalid() {
+        let mut vss = PedersenVSS::new(3, 5, None).unwrap();
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+
+        let mut tampered = response.shares[0].clone();
+        tampered.1 += 1;
+        let blinding = response.blinding_shares[0].1.clone();
+        assert!(
+            !vss.validate_shares(tampered, blinding.clone()),
+            "Tampered share should be invalid"
+        );
+        assert!(
+            !vss.validate_shares(response.shares[0].clone(), blinding + 1),
+            "Tampered blinding share should be invalid"
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_secret() {
+        let mut vss = PedersenVSS::new(3, 5, None).unwrap();
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            vss.reconstruct(&response.shares[1..4]).unwrap(),
+            BigInt::from(1234),
+            "Reconstructed secret should match the original secret"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use alloc::{vec, vec::Vec};
+
+use num_bigint::{BigInt, RandBigInt};
+use rand::Rng;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    // a0 + a1*x + ... + ad*x^d, a0 is the secret
+    pub coefficients: Vec<BigInt>,
+    pub prime: BigInt,
+}
+
+impl Polynomial {
+    pub fn new(coefficients: Vec<BigInt>, prime: BigInt) -> Self {
+        Self {
+            coefficients,
+            prime,
+        }
+    }
+
+    // polynomial of the given degree with a0 = secret and random coefficients in [1,prime)
+    pub fn random<R: Rng + ?Sized>(
+        degree: usize,
+        secret: BigInt,
+        prime: &BigInt,
+        rng: &mut R,
+    ) -> Self {
+        let mut coefficients = vec![secret];
+        for _i in 0..degree {
+            let new_coefficient = rng.gen_bigint_range(&BigInt::from(1), prime);
+            coefficients.push(new_coefficient);
+        }
+        Self::new(coefficients, prime.clone())
+    }
+
+    // f(x) mod prime by horner's rule, reduced on every step so the accumulator never grows
+    // past prime * x + prime, whatever the degree
+    pub fn evaluate(&self, x: usize) -> BigInt {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigInt::from(0), |acc, coeff| {
+                (acc * x + coeff) % &self.prime
+            })
+    }
+
+    // f(x) mod prime given powers[j] = x^j for every coefficient, e.g. from a per index cache
+    pub fn evaluate_with_powers(&self, powers: &[BigInt]) -> BigInt {
+        self.coefficients
+            .iter()
+            .zip(powers.iter())
+            .map(|(coeff, power)| coeff * power)
+            .sum::<BigInt>()
+            % &self.prime
+    }
+
+    // degree implied by the number of coefficients
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    // drop every coefficient, a0 is the secret. this only frees them: num-bigint gives no
+    // mutable access to its digit buffers, so their memory is released without being scrubbed
+    pub fn clear(&mut self) {
+        self.coefficients.clear();
+    }
+}
+
+// f(x) mod prime for every x in [start, end] using forward differences: after the first
+// degree + 1 values each next value only costs degree additions, no multiplications
+pub fn shares_for_range(
+    coeffs: &[BigInt],
+    start: usize,
+    end: usize,
+    prime: &BigInt,
+) -> Vec<(usize, BigInt)> {
+    if start > end || coeffs.is_empty() {
+        return Vec::new();
+    }
+    let degree = coeffs.len() - 1;
+    let poly = Polynomial::new(coeffs.to_vec(), prime.clone());
+
+    // differences[k] holds the k-th forward difference at the current x
+    let mut differences: Vec<BigInt> = (0..=degree)
+        .map(|k| poly.evaluate(start + k) % prime)
+        .collect();
+    for k in 1..=degree {
+        for j in (k..=degree).rev() {
+            differences[j] = (&differences[j] - &differences[j - 1]) % prime;
+        }
+    }
+
+    let mut shares = Vec::with_capacity(end - start + 1);
+    for x in start..=end {
+        shares.push((x, ((&differences[0] % prime) + prime) % prime));
+        // step every difference forward by one
+        for k in 0..degree {
+            differences[k] = (&differences[k] + &differences[k + 1]) % prime;
+        }
+    }
+    shares
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::polynomial::{shares_for_range, Polynomial};
+    use crate::algorithms::shamir_secret_sharing::default_prime;
+    use num_bigint::BigInt;
+
+    fn poly(coefficients: &[i64]) -> Polynomial {
+        Polynomial::new(
+            coefficients.iter().map(|c| BigInt::from(*c)).collect(),
+            default_prime(),
+        )
+    }
+
+    #[test]
+    fn evaluate_test() {
+        // f(x) = 3 + 2x + x^2
+        let f = poly(&[3, 2, 1]);
+        assert_eq!(f.evaluate(0), BigInt::from(3), "f(0) should be the secret");
+        assert_eq!(f.evaluate(1), BigInt::from(6));
+        assert_eq!(f.evaluate(2), BigInt::from(11));
+        assert_eq!(f.evaluate(10), BigInt::from(123));
+
+        // f(x) = 7 + 5x^3
+        let g = poly(&[7, 0, 0, 5]);
+        assert_eq!(g.evaluate(3), BigInt::from(142));
+    }
+
+    #[test]
+    fn evaluate_reduces_test() {
+        // 3 + 2 * 10 + 10^2 = 123 = 11 * 11 + 2
+        let f = Polynomial::new(
+            [3, 2, 1].iter().map(|c| BigInt::from(*c)).collect(),
+            BigInt::from(11),
+        );
+        assert_eq!(f.evaluate(10), BigInt::from(2), "f(x) should be mod prime");
+        let powers: Vec<BigInt> = [1, 10, 100].iter().map(|p| BigInt::from(*p)).collect();
+        assert_eq!(f.evaluate_with_powers(&powers), BigInt::from(2));
+
+        let prime = default_prime();
+        let f = Polynomial::random(49, BigInt::from(1234), &prime, &mut rand::thread_rng());
+        for x in [1, 2, 1000, usize::MAX] {
+            assert!(f.evaluate(x) < prime, "f({}) should be below the prime", x);
+        }
+    }
+
+    #[test]
+    fn evaluate_high_degree_test() {
+        let prime = default_prime();
+        let f = Polynomial::random(49, BigInt::from(1234), &prime, &mut rand::thread_rng());
+        for x in [1, 2, 17, 60] {
+            let powers: Vec<BigInt> = (0..50).map(|i| BigInt::from(x).pow(i)).collect();
+            assert_eq!(
+                f.evaluate(x as usize),
+                f.evaluate_with_powers(&powers),
+                "Horner should match the power sum at {}",
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn degree_test() {
+        assert_eq!(poly(&[3, 2, 1]).degree(), 2);
+        assert_eq!(poly(&[3]).degree(), 0);
+        assert_eq!(poly(&[]).degree(), 0);
+    }
+
+    #[test]
+    fn random_test() {
+        let prime = default_prime();
+        let f = Polynomial::random(4, BigInt::from(1234), &prime, &mut rand::thread_rng());
+        assert_eq!(
+            f.degree(),
+            4,
+            "Random polynomial should have the asked degree"
+        );
+        assert_eq!(
+            f.coefficients[0],
+            BigInt::from(1234),
+            "a0 should be the secret"
+        );
+        for coeff in f.coefficients[1..].iter() {
+            assert!(
+                *coeff >= BigInt::from(1) && *coeff < prime,
+                "Coefficients should be in [1,prime)"
+            );
+        }
+    }
+
+    #[test]
+    fn shares_for_range_test() {
+        let prime = default_prime();
+        let f = Polynomial::random(5, BigInt::from(1234), &prime, &mut rand::thread_rng());
+
+        let shares = shares_for_range(&f.coefficients, 1, 100, &prime);
+        assert_eq!(
+            shares.len(),
+            100,
+            "Every index in the range should get a share"
+        );
+        for (x, y) in shares {
+            // horner evaluation mod prime per index
+            let expected = f
+                .coefficients
+                .iter()
+                .rev()
+                .fold(BigInt::from(0), |acc, c| (acc * x + c) % &prime);
+            assert_eq!(y, expected, "Share at {} should match horner evaluation", x);
+        }
+    }
+
+    #[test]
+    fn shares_for_range_edges_test() {
+        let prime = BigInt::from(11);
+        let constant = [BigInt::from(4)];
+        assert_eq!(
+            shares_for_range(&constant, 3, 5, &prime),
+            vec![
+                (3, BigInt::from(4)),
+                (4, BigInt::from(4)),
+                (5, BigInt::from(4))
+            ]
+        );
+        assert!(shares_for_range(&constant, 5, 3, &prime).is_empty());
+    }
+
+    #[test]
+    fn clear_test() {
+        let mut f = poly(&[1234, 5, 6]);
+        f.clear();
+        assert!(f.coefficients.is_empty(), "Clear should drop coefficients");
+        assert_eq!(f.evaluate(3), BigInt::from(0));
+    }
+
+    #[test]
+    fn evaluate_with_powers_test() {
+        let f = poly(&[3, 2, 1]);
+        let powers: Vec<BigInt> = [1, 10, 100].iter().map(|p| BigInt::from(*p)).collect();
+        assert_eq!(f.evaluate_with_powers(&powers), f.evaluate(10));
+    }
+}
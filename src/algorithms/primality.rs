@@ -0,0 +1,111 @@
+use num_bigint::BigInt;
+
+// the first 12 primes as miller-rabin witnesses, deterministic for n < 3.3 * 10^24 and a
+// negligible error probability beyond that
+const WITNESSES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// miller-rabin primality test with a fixed witness set
+pub fn is_prime(n: &BigInt) -> bool {
+    let one = BigInt::from(1);
+    let two = BigInt::from(2);
+    if *n < two {
+        return false;
+    }
+    // trial division by the witnesses handles the small cases
+    for w in WITNESSES {
+        let w = BigInt::from(w);
+        if *n == w {
+            return true;
+        }
+        if n % &w == BigInt::from(0) {
+            return false;
+        }
+    }
+
+    // n - 1 = d * 2^s with d odd
+    let n_minus_one = n - &one;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    'witness: for w in WITNESSES {
+        let mut x = BigInt::from(w).modpow(&d, n);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.modpow(&two, n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+// smallest prime strictly greater than n
+pub fn next_prime(n: &BigInt) -> BigInt {
+    let two = BigInt::from(2);
+    if *n < two {
+        return two;
+    }
+    // step through odd candidates only
+    let mut candidate: BigInt = n + 1;
+    if !candidate.bit(0) {
+        candidate += 1;
+    }
+    while !is_prime(&candidate) {
+        candidate += 2;
+    }
+    candidate
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::primality::{is_prime, next_prime};
+    use num_bigint::BigInt;
+
+    #[test]
+    fn small_numbers_test() {
+        let primes: Vec<u32> = (0..100u32)
+            .filter(|n| is_prime(&BigInt::from(*n)))
+            .collect();
+        assert_eq!(
+            primes,
+            vec![
+                2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79,
+                83, 89, 97
+            ],
+            "Should find exactly the primes below 100"
+        );
+    }
+
+    #[test]
+    fn large_numbers_test() {
+        assert!(is_prime(&BigInt::from(2147483647)), "2^31 - 1 is prime");
+        // carmichael number and a strong pseudoprime to base 2
+        assert!(!is_prime(&BigInt::from(561)), "561 is composite");
+        assert!(
+            !is_prime(&BigInt::from(2047)),
+            "2047 = 23 * 89 is composite"
+        );
+        let p256 = BigInt::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .unwrap();
+        assert!(is_prime(&p256), "secp256k1 field prime is prime");
+        assert!(!is_prime(&(&p256 * 3)), "multiple of a prime is composite");
+    }
+
+    #[test]
+    fn next_prime_test() {
+        assert_eq!(next_prime(&BigInt::from(0)), BigInt::from(2));
+        assert_eq!(next_prime(&BigInt::from(2)), BigInt::from(3));
+        assert_eq!(next_prime(&BigInt::from(13)), BigInt::from(17));
+        assert_eq!(
+            next_prime(&BigInt::from(2147483646)),
+            BigInt::from(2147483647)
+        );
+    }
+}
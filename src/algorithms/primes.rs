@@ -0,0 +1,55 @@
+use num_bigint::BigInt;
+
+use super::shamir_secret_sharing::default_prime;
+
+// well known primes for the common field sizes, e.g.
+// ShamirSecretSharing::new(t, n, Some(primes::p256()))
+
+// 2^31 - 1, the default prime. 3 byte blocks in split_bytes
+pub fn p32() -> BigInt {
+    default_prime()
+}
+
+// 2^127 - 1, a mersenne prime. 15 byte blocks in split_bytes
+pub fn p128() -> BigInt {
+    (BigInt::from(1) << 127) - 1
+}
+
+// 2^256 - 2^224 + 2^192 + 2^96 - 1, the nist p-256 field prime. 31 byte blocks in split_bytes
+pub fn p256() -> BigInt {
+    let one = BigInt::from(1);
+    (&one << 256) - (&one << 224) + (&one << 192) + (&one << 96) - 1
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::primality::is_prime;
+    use crate::algorithms::primes::{p128, p256, p32};
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn presets_are_prime_test() {
+        for (name, prime, bits) in [
+            ("p32", p32(), 31),
+            ("p128", p128(), 127),
+            ("p256", p256(), 256),
+        ] {
+            assert!(is_prime(&prime), "{} should be prime", name);
+            assert_eq!(prime.bits(), bits, "{} should have {} bits", name, bits);
+        }
+        assert_eq!(p32(), BigInt::from(2147483647));
+    }
+
+    #[test]
+    fn p256_round_trip_test() {
+        let secret: BigInt = p256() - 12345;
+        let mut shamir = ShamirSecretSharing::new(3, 5, Some(p256())).unwrap();
+        let shares = shamir.generate_shares(secret.clone()).unwrap();
+        assert_eq!(
+            shamir.reconstruct(&shares[2..]).unwrap(),
+            secret,
+            "A 256 bit secret should round trip"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
+
+use super::shamir_secret_sharing::{mod_inverse, ShamirSecretSharing};
+use crate::error::SecretSharingError;
+
+// one lagrange term: partial_sum = previous partial_sum + lagrange_coefficient * share mod prime
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    pub index: usize,
+    pub share: BigInt,
+    pub lagrange_coefficient: BigInt,
+    pub term: BigInt,
+    pub partial_sum: BigInt,
+}
+
+// reconstruction spelled out as field operations so a circuit can mirror every step.
+// all values are reduced into [0, prime)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconstructionTrace {
+    pub prime: BigInt,
+    pub steps: Vec<TraceStep>,
+    pub secret: BigInt,
+}
+
+impl ReconstructionTrace {
+    // recheck every step from its inputs: the coefficient against the indices, the term, the
+    // running sum and the final secret
+    pub fn is_consistent(&self) -> bool {
+        let prime = &self.prime;
+        let reduce = |v: BigInt| ((v % prime) + prime) % prime;
+        let mut sum = BigInt::from(0);
+        for (i, step) in self.steps.iter().enumerate() {
+            // lambda_i * prod (xi - xj) == prod (0 - xj)
+            let (num, denom) = self.steps.iter().enumerate().filter(|(j, _)| *j != i).fold(
+                (BigInt::from(1), BigInt::from(1)),
+                |(num, denom), (_, other)| {
+                    let xi = BigInt::from(step.index);
+                    let xj = BigInt::from(other.index);
+                    (num * -&xj, denom * (xi - xj))
+                },
+            );
+            if reduce(&step.lagrange_coefficient * denom) != reduce(num) {
+                return false;
+            }
+            if step.term != reduce(&step.lagrange_coefficient * &step.share) {
+                return false;
+            }
+            sum = reduce(sum + &step.term);
+            if step.partial_sum != sum {
+                return false;
+            }
+        }
+        sum == self.secret
+    }
+}
+
+impl ShamirSecretSharing {
+    // same result as reconstruct with every intermediate value kept: one step per share, all of
+    // them interpolated, and the secret is the last partial sum
+    pub fn reconstruct_trace(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<ReconstructionTrace, SecretSharingError> {
+        self.check_shares(shares)?;
+        let prime = &self.prime;
+        let reduce = |v: BigInt| ((v % prime) + prime) % prime;
+        let points = shares;
+
+        let mut steps = Vec::with_capacity(points.len());
+        let mut partial_sum = BigInt::from(0);
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut num = BigInt::from(1);
+            let mut denom = BigInt::from(1);
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    num = reduce(num * -BigInt::from(*xj));
+                    denom = reduce(denom * (BigInt::from(*xi) - BigInt::from(*xj)));
+                }
+            }
+            let inverse = mod_inverse(&denom, prime).expect("share indices must be distinct");
+            let lagrange_coefficient = reduce(num * inverse);
+            let share = reduce(yi.clone());
+            let term = reduce(&lagrange_coefficient * &share);
+            partial_sum = reduce(partial_sum + &term);
+            steps.push(TraceStep {
+                index: *xi,
+                share,
+                lagrange_coefficient,
+                term,
+                partial_sum: partial_sum.clone(),
+            });
+        }
+        Ok(ReconstructionTrace {
+            prime: prime.clone(),
+            steps,
+            secret: partial_sum,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn reconstruct_trace_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let trace = shamir.reconstruct_trace(&shares[1..]).unwrap();
+
+        assert_eq!(trace.steps.len(), 4, "One step per interpolated share");
+        assert_eq!(trace.secret, BigInt::from(1234));
+        assert_eq!(
+            trace.steps.last().unwrap().partial_sum,
+            trace.secret,
+            "Final partial sum should be the secret"
+        );
+        assert!(trace.is_consistent(), "Honest trace should be consistent");
+
+        // a share off the polynomial changes reconstruct and the trace the same way
+        let mut corrupted = shares.clone();
+        corrupted[4].1 += 1;
+        assert_eq!(
+            shamir.reconstruct_trace(&corrupted).unwrap().secret,
+            shamir.reconstruct_tuples(&corrupted).unwrap(),
+            "Trace should interpolate the same shares as reconstruct"
+        );
+
+        let mut tampered = trace.clone();
+        tampered.steps[1].lagrange_coefficient += 1;
+        assert!(
+            !tampered.is_consistent(),
+            "Wrong lagrange coefficient should be caught"
+        );
+        let mut tampered = trace.clone();
+        tampered.steps[0].partial_sum += 1;
+        assert!(
+            !tampered.is_consistent(),
+            "Wrong partial sum should be caught"
+        );
+    }
+}
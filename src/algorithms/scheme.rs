@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
+
+use super::feldman_vss::FeldmanVSS;
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+// common interface over the schemes so generic code can deal and reconstruct without knowing
+// which one it holds
+pub trait SecretSharing {
+    fn generate_shares(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError>;
+
+    fn reconstruct(&self, shares: &[(usize, BigInt)]) -> Result<BigInt, SecretSharingError>;
+}
+
+impl SecretSharing for ShamirSecretSharing {
+    fn generate_shares(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        ShamirSecretSharing::generate_shares_tuples(self, secret)
+    }
+
+    fn reconstruct(&self, shares: &[(usize, BigInt)]) -> Result<BigInt, SecretSharingError> {
+        ShamirSecretSharing::reconstruct_tuples(self, shares)
+    }
+}
+
+// only the shares come back through the trait, the dealer's commitments stay available from
+// public_audit_record
+impl SecretSharing for FeldmanVSS {
+    fn generate_shares(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        Ok(FeldmanVSS::generate_shares(self, secret)?.shares)
+    }
+
+    fn reconstruct(&self, shares: &[(usize, BigInt)]) -> Result<BigInt, SecretSharingError> {
+        FeldmanVSS::reconstruct(self, shares)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::feldman_vss::FeldmanVSS;
+    use crate::algorithms::scheme::SecretSharing;
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn trait_object_test() {
+        let mut schemes: Vec<Box<dyn SecretSharing>> = vec![
+            Box::new(ShamirSecretSharing::new(3, 5, None).unwrap()),
+            Box::new(FeldmanVSS::new(3, 5, None).unwrap()),
+        ];
+        for scheme in schemes.iter_mut() {
+            let shares = scheme.generate_shares(BigInt::from(1234)).unwrap();
+            assert_eq!(shares.len(), 5);
+            assert_eq!(
+                scheme.reconstruct(&shares[1..4]).unwrap(),
+                BigInt::from(1234),
+                "Every scheme should round trip through the trait"
+            );
+        }
+    }
+
+    #[test]
+    fn feldman_commitments_after_trait_dealing_test() {
+        let mut vss = FeldmanVSS::new(2, 3, None).unwrap();
+        let shares = SecretSharing::generate_shares(&mut vss, BigInt::from(77)).unwrap();
+        let record = vss.public_audit_record();
+        assert_eq!(record.committments.len(), 2);
+        assert!(record.verify_share(shares[0].clone()));
+    }
+}
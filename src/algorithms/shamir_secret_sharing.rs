@@ -1,12 +1,173 @@
-use num_bigint::{BigInt, RandBigInt};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use num_bigint::{BigInt, Sign};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+#[cfg(feature = "std")]
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+use super::field::FieldElement;
+use super::polynomial::Polynomial;
+use super::primality::{is_prime, next_prime};
+use super::share::Share;
+use crate::error::SecretSharingError;
+
+// upper bound on coefficient regenerations in generate_shares_nonzero
+#[cfg(feature = "std")]
+const MAX_NONZERO_RETRIES: usize = 100;
+
+// 2^31 - 1, the prime used when none is given
+pub const DEFAULT_PRIME_U32: u32 = 2147483647;
+
+// above this many shares each x is evaluated on the rayon thread pool
+#[cfg(feature = "std")]
+const PARALLEL_SHARE_CUTOFF: usize = 10;
 
-#[derive(Debug)]
 pub struct ShamirSecretSharing {
     pub threshold: usize,
     pub total_shares: usize,
     pub prime: BigInt,
-    pub coefficients: Vec<BigInt>,
+    pub polynomial: Polynomial,
+    // index_powers[i - 1][j] = i^j mod prime for every share index, see precompute_index_powers
+    index_powers: Option<Vec<Vec<BigInt>>>,
+}
+
+// coefficients[0] is the secret, so only their count is printed. keeps logged instances (and
+// the feldman and pedersen dealers holding one) from leaking it
+impl fmt::Debug for ShamirSecretSharing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShamirSecretSharing")
+            .field("threshold", &self.threshold)
+            .field("total_shares", &self.total_shares)
+            .field("prime", &self.prime)
+            .field(
+                "coefficients",
+                &format_args!("[REDACTED; {}]", self.polynomial.coefficients.len()),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+// clones the configuration only. the coefficients (and with them the secret) are not copied,
+// so a clone starts without a dealing and never holds a second copy of the secret
+impl Clone for ShamirSecretSharing {
+    fn clone(&self) -> Self {
+        Self {
+            threshold: self.threshold,
+            total_shares: self.total_shares,
+            prime: self.prime.clone(),
+            polynomial: Polynomial::new(Vec::new(), self.prime.clone()),
+            index_powers: self.index_powers.clone(),
+        }
+    }
+}
+
+// upper bounds on threshold and share count so untrusted parameters cannot make polynomial
+// generation and interpolation exhaust memory or cpu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShareLimits {
+    pub max_threshold: usize,
+    pub max_total_shares: usize,
+}
+
+impl Default for ShareLimits {
+    fn default() -> Self {
+        Self {
+            max_threshold: 1024,
+            max_total_shares: 4096,
+        }
+    }
+}
+
+impl ShareLimits {
+    // raise (or lower) the threshold limit, for trusted callers
+    pub fn max_threshold(mut self, max_threshold: usize) -> Self {
+        self.max_threshold = max_threshold;
+        self
+    }
+
+    // raise (or lower) the share count limit, for trusted callers
+    pub fn max_total_shares(mut self, max_total_shares: usize) -> Self {
+        self.max_total_shares = max_total_shares;
+        self
+    }
+}
+
+// only the public configuration is persisted. the polynomial holds the secret in a0 and is
+// never written out, a deserialized instance starts without coefficients
+impl Serialize for ShamirSecretSharing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ShamirSecretSharing", 3)?;
+        state.serialize_field("threshold", &self.threshold)?;
+        state.serialize_field("total_shares", &self.total_shares)?;
+        state.serialize_field("prime", &self.prime)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ShamirSecretSharing {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Config {
+            threshold: usize,
+            total_shares: usize,
+            prime: BigInt,
+        }
+        let config = Config::deserialize(deserializer)?;
+        // persisted state goes through the same validation as new
+        Self::new(config.threshold, config.total_shares, Some(config.prime))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+// named alternative to the positional (threshold, total_shares, prime) constructor,
+// unset counts are 0 and fail the same validation as new
+#[derive(Debug, Clone, Default)]
+pub struct ShamirBuilder {
+    threshold: usize,
+    total_shares: usize,
+    prime: Option<BigInt>,
+    limits: ShareLimits,
+}
+
+impl ShamirBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn total_shares(mut self, total_shares: usize) -> Self {
+        self.total_shares = total_shares;
+        self
+    }
+
+    pub fn prime(mut self, prime: BigInt) -> Self {
+        self.prime = Some(prime);
+        self
+    }
+
+    pub fn limits(mut self, limits: ShareLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    pub fn build(self) -> Result<ShamirSecretSharing, SecretSharingError> {
+        ShamirSecretSharing::new_with_limits(
+            self.threshold,
+            self.total_shares,
+            self.prime,
+            self.limits,
+        )
+    }
 }
 
 impl ShamirSecretSharing {
@@ -14,122 +175,906 @@ impl ShamirSecretSharing {
         threshold: usize,
         total_shares: usize,
         prime: Option<BigInt>,
-    ) -> Result<Self, String> {
-        if threshold > total_shares {
-            return Err("Threshold has to be less than total shares!".to_string());
-        }
+    ) -> Result<Self, SecretSharingError> {
+        Self::new_with_limits(threshold, total_shares, prime, ShareLimits::default())
+    }
 
-        let prime = if let Some(p) = prime {
-            p
+    // instance whose prime is large enough for the secret: the default prime when the secret
+    // fits, otherwise the next prime above the secret
+    pub fn for_secret(
+        threshold: usize,
+        total_shares: usize,
+        secret: &BigInt,
+    ) -> Result<Self, SecretSharingError> {
+        let default_prime = default_prime();
+        let prime = if *secret < default_prime {
+            default_prime
         } else {
-            BigInt::from(2147483647)
+            next_prime(secret)
         };
+        Self::new(threshold, total_shares, Some(prime))
+    }
+
+    // same threshold, share count and prime, whatever either instance has dealt
+    pub fn config_eq(&self, other: &Self) -> bool {
+        self.threshold == other.threshold
+            && self.total_shares == other.total_shares
+            && self.prime == other.prime
+    }
+
+    // same as new but with caller supplied limits instead of the defaults
+    pub fn new_with_limits(
+        threshold: usize,
+        total_shares: usize,
+        prime: Option<BigInt>,
+        limits: ShareLimits,
+    ) -> Result<Self, SecretSharingError> {
+        let shamir = Self::new_unchecked_with_limits(threshold, total_shares, prime, limits)?;
+        // composite moduli have no inverses for some denominators and break reconstruction
+        if !is_prime(&shamir.prime) {
+            return Err(SecretSharingError::NonPrimeModulus {
+                modulus: shamir.prime,
+            });
+        }
+        Ok(shamir)
+    }
+
+    // same as new but trusts the caller that the modulus is prime
+    pub fn new_unchecked(
+        threshold: usize,
+        total_shares: usize,
+        prime: Option<BigInt>,
+    ) -> Result<Self, SecretSharingError> {
+        Self::new_unchecked_with_limits(threshold, total_shares, prime, ShareLimits::default())
+    }
+
+    fn new_unchecked_with_limits(
+        threshold: usize,
+        total_shares: usize,
+        prime: Option<BigInt>,
+        limits: ShareLimits,
+    ) -> Result<Self, SecretSharingError> {
+        // a zero threshold would underflow threshold - 1 in generate_polynomial
+        if threshold == 0 {
+            return Err(SecretSharingError::ZeroThreshold);
+        }
+        if threshold > total_shares {
+            return Err(SecretSharingError::ThresholdExceedsShares {
+                threshold,
+                total_shares,
+            });
+        }
+        if threshold > limits.max_threshold {
+            return Err(SecretSharingError::ThresholdAboveLimit {
+                threshold,
+                max: limits.max_threshold,
+            });
+        }
+        if total_shares > limits.max_total_shares {
+            return Err(SecretSharingError::TotalSharesAboveLimit {
+                total_shares,
+                max: limits.max_total_shares,
+            });
+        }
+
+        let prime = prime.unwrap_or_else(default_prime);
 
         if prime <= BigInt::from(0) {
-            return Err("Prime should not less than 1".to_string());
+            return Err(SecretSharingError::NonPrimeModulus { modulus: prime });
+        }
+        // gf(2) only holds the secrets 0 and 1 and below that there is no field at all
+        if prime < BigInt::from(3) {
+            return Err(SecretSharingError::PrimeTooSmall { prime });
+        }
+        // indices 1..=total_shares have to be distinct non zero field elements, so the largest
+        // one must stay strictly below the prime
+        if BigInt::from(total_shares) >= prime {
+            return Err(SecretSharingError::SharesExceedPrime {
+                total_shares,
+                prime,
+            });
         }
 
         Ok(Self {
             threshold,
             total_shares,
+            polynomial: Polynomial::new(Vec::new(), prime.clone()),
             prime,
-            coefficients: Vec::new(),
+            index_powers: None,
         })
     }
 
     // generates shares based on the secret, n and k
-    pub fn generate_shares(&mut self, secret: BigInt) -> Result<Vec<(usize, BigInt)>, String> {
-        if secret >= self.prime {
-            return Err("Secret can't be larger than ".to_string() + &self.prime.to_string());
-        }
+    #[cfg(feature = "std")]
+    pub fn generate_shares(&mut self, secret: BigInt) -> Result<Vec<Share>, SecretSharingError> {
+        Ok(self
+            .generate_shares_tuples(secret)?
+            .into_iter()
+            .map(Share::from)
+            .collect())
+    }
+
+    // generate_shares as (index, value) tuples, kept for callers of the tuple api
+    #[cfg(feature = "std")]
+    pub fn generate_shares_tuples(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        self.generate_shares_with_rng(secret, &mut rand::thread_rng())
+    }
+
+    // same shares for the same seed, for tests and protocols with agreed randomness
+    pub fn generate_shares_seeded(
+        &mut self,
+        secret: BigInt,
+        seed: [u8; 32],
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        self.generate_shares_with_rng(secret, &mut ChaCha20Rng::from_seed(seed))
+    }
 
-        // update self.coefficients
-        self.generate_coefficients(secret);
+    // generates shares drawing the polynomial coefficients from the given rng
+    pub fn generate_shares_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        secret: BigInt,
+        rng: &mut R,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        self.check_secret(&secret)?;
+
+        // update self.polynomial
+        self.generate_polynomial(secret, rng);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            shares = self.total_shares,
+            degree = self.threshold - 1,
+            "generating shares"
+        );
+        // larger shares need thread pool
+        #[cfg(feature = "std")]
+        if self.total_shares > PARALLEL_SHARE_CUTOFF {
+            return Ok((1..=self.total_shares)
+                .into_par_iter()
+                .map(|i| (i, self.calculate_y(i)))
+                .collect());
+        }
+        // use serial processing, always when built without the std feature
         let mut shares = Vec::new();
-        // use serial processing
-        if self.total_shares <= 10 {
-            for i in 1..=self.total_shares {
-                shares.push((i, self.calculate_y(i)));
+        for i in 1..=self.total_shares {
+            shares.push((i, self.calculate_y(i)));
+        }
+        Ok(shares)
+    }
+
+    // generates shares at caller chosen x coordinates (e.g. participant ids) instead of 1..=n
+    #[cfg(feature = "std")]
+    pub fn generate_shares_with_indices(
+        &mut self,
+        secret: BigInt,
+        indices: &[usize],
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        let reduced: Vec<BigInt> = indices
+            .iter()
+            .map(|i| BigInt::from(*i) % &self.prime)
+            .collect();
+        for (n, index) in indices.iter().enumerate() {
+            // f(0) is the secret itself, and so is f(k * prime) in the field
+            if reduced[n] == BigInt::from(0) {
+                return Err(SecretSharingError::InvalidShareIndex { index: *index });
+            }
+            if indices[..n].contains(index) {
+                return Err(SecretSharingError::DuplicateShareIndex { index: *index });
+            }
+            // distinct integers can still be the same field element
+            if let Some(m) = reduced[..n].iter().position(|other| *other == reduced[n]) {
+                return Err(SecretSharingError::CongruentShareIndices {
+                    first: BigInt::from(indices[m]),
+                    second: BigInt::from(*index),
+                });
             }
-            Ok(shares)
-        } else {
-            // larger shares need thread pool
-            shares = (1..=self.total_shares)
-                .into_par_iter()
-                .map(|i| {
-                    let x_value = BigInt::from(i);
-                    let mut result = BigInt::from(0);
-                    for (i, coeff) in self.coefficients.iter().enumerate() {
-                        result = result + (coeff * x_value.pow(i as u32));
-                    }
-                    (i, result)
-                })
-                .collect();
-            Ok(shares)
         }
+        self.check_secret(&secret)?;
+
+        self.generate_polynomial(secret, &mut rand::thread_rng());
+        Ok(indices
+            .iter()
+            .map(|i| (*i, self.polynomial.evaluate(*i)))
+            .collect())
     }
 
-    // calculate y for f(i)
-    fn calculate_y(&self, x: usize) -> BigInt {
-        let coefficients = &self.coefficients;
-        let x_value = BigInt::from(x);
-        let mut result = BigInt::from(0);
-        for (i, coeff) in coefficients.iter().enumerate() {
-            result = result + (coeff * x_value.pow(i as u32));
+    // generates shares reduced mod prime, regenerating coefficients until no share is zero
+    #[cfg(feature = "std")]
+    pub fn generate_shares_nonzero(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        for _ in 0..MAX_NONZERO_RETRIES {
+            let shares = self.generate_shares_tuples(secret.clone())?;
+            if shares.iter().all(|(_, y)| *y != BigInt::from(0)) {
+                return Ok(shares);
+            }
         }
-        result
+        Err(SecretSharingError::NonzeroSharesExhausted {
+            attempts: MAX_NONZERO_RETRIES,
+        })
     }
 
-    // generate random coefficients of the polynomial with [1,prime)
-    fn generate_coefficients(&mut self, secret: BigInt) {
-        // a0 = secret
-        let mut coefficients = vec![secret];
-        let mut rng = rand::thread_rng();
-        for _i in 0..self.threshold - 1 {
-            let new_coefficient = rng.gen_bigint_range(&BigInt::from(1), &self.prime);
-            coefficients.push(new_coefficient);
+    // generates shares plus a trailing canary share (index n+1) whose value is a hash of all
+    // other shares. the canary only detects tampering of the set (integrity), it adds no
+    // confidentiality and is not a point on the polynomial, so strip it before reconstructing
+    #[cfg(feature = "std")]
+    pub fn generate_shares_with_canary(
+        &mut self,
+        secret: BigInt,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        let mut shares = self.generate_shares_tuples(secret)?;
+        let canary = canary_value(&shares);
+        shares.push((self.total_shares + 1, canary));
+        Ok(shares)
+    }
+
+    // recompute the canary over all but the last share and compare it with the last share
+    pub fn verify_canary(shares_with_canary: &[(usize, BigInt)]) -> bool {
+        match shares_with_canary.split_last() {
+            Some(((_, canary), shares)) => *canary == canary_value(shares),
+            None => false,
+        }
+    }
+
+    // drop the dealing polynomial, including the secret in a0, once the shares are out. see
+    // Polynomial::clear, the bigint digits are freed but not scrubbed
+    pub fn clear_coefficients(&mut self) {
+        self.polynomial.clear();
+    }
+
+    // pre-flight check that the dealing polynomial still has the secret as its constant term,
+    // false once the coefficients are cleared or before any shares were generated
+    pub fn verify_constant_term(&self, expected_secret: &BigInt) -> bool {
+        self.polynomial.coefficients.first() == Some(expected_secret)
+    }
+
+    // cache i^j mod prime for every share index and coefficient so repeated dealings with the
+    // same (threshold, total_shares) skip the exponentiations. every entry stays below the prime
+    // and evaluate_with_powers reduces the sum, so shares match the uncached path
+    pub fn precompute_index_powers(&mut self) {
+        let powers = (1..=self.total_shares)
+            .map(|i| {
+                let x = BigInt::from(i);
+                let mut row = Vec::with_capacity(self.threshold);
+                let mut power = BigInt::from(1);
+                for _ in 0..self.threshold {
+                    row.push(power.clone());
+                    power = power * &x % &self.prime;
+                }
+                row
+            })
+            .collect();
+        self.index_powers = Some(powers);
+    }
+
+    // f(i) for a share index, from the power cache when it has been precomputed
+    fn calculate_y(&self, i: usize) -> BigInt {
+        match self
+            .index_powers
+            .as_ref()
+            .and_then(|p| p.get(i.wrapping_sub(1)))
+        {
+            Some(powers) => self.polynomial.evaluate_with_powers(powers),
+            None => self.polynomial.evaluate(i),
+        }
+    }
+
+    // random polynomial of degree t-1 with a0 = secret
+    fn generate_polynomial<R: Rng + ?Sized>(&mut self, secret: BigInt, rng: &mut R) {
+        self.polynomial = Polynomial::random(self.threshold - 1, secret, &self.prime, rng);
+    }
+
+    // lagrange interpolation of f(0) through every given point. consistent extra points lie on
+    // the same degree threshold - 1 polynomial and leave the result unchanged. x coordinates
+    // are converted straight from usize and reduced mod prime, so any index up to usize::MAX
+    // works as long as no two of them are congruent
+    pub fn lagrange_interpolation(
+        &self,
+        xs: Vec<usize>,
+        ys: Vec<BigInt>,
+    ) -> Result<BigInt, SecretSharingError> {
+        // zipping moves the values, nothing is cloned
+        let shares: Vec<(usize, BigInt)> = xs.into_iter().zip(ys).collect();
+        check_congruent_indices(&shares, &self.prime)?;
+        Ok(self.interpolate_first(&shares, shares.len(), &BigInt::from(0)))
+    }
+
+    // f(at) from the first count shares, borrowing the share values. field elements stay in
+    // [0, prime) throughout, so the result needs no sign correction
+    fn interpolate_first(&self, shares: &[(usize, BigInt)], count: usize, at: &BigInt) -> BigInt {
+        let at = FieldElement::new(at, &self.prime);
+        let points: Vec<(FieldElement, FieldElement)> = shares[..count]
+            .iter()
+            .map(|(x, y)| {
+                (
+                    FieldElement::from_index(*x, &self.prime),
+                    FieldElement::new(y, &self.prime),
+                )
+            })
+            .collect();
+        let mut value = FieldElement::zero(&self.prime);
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut num = FieldElement::one(&self.prime);
+            let mut denom = FieldElement::one(&self.prime);
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    // (at-xj)
+                    num = &num * &(&at - xj);
+                    // (xi-xj)
+                    denom = &denom * &(xi - xj);
+                }
+            }
+            // the whole basis coefficient (at-xj)/(xi-xj) as num * denom^-1 in the field, only
+            // then scaled by yi and summed
+            let inverse = denom.inverse().expect("share indices must be distinct");
+            let coefficient = &num * &inverse;
+            value = &value + &(&coefficient * yi);
         }
-        self.coefficients = coefficients;
+        value.to_bigint()
     }
 
-    // lagrange interpolation to reconstruct poly from t shares
-    pub fn lagrange_interpolation(&self, xs: Vec<usize>, ys: Vec<BigInt>) -> BigInt {
+    // the secret has to be a field element. a negative one would come back as secret + prime
+    pub(crate) fn check_secret(&self, secret: &BigInt) -> Result<(), SecretSharingError> {
+        if secret.sign() == Sign::Minus {
+            return Err(SecretSharingError::NegativeSecret);
+        }
+        if *secret >= self.prime {
+            return Err(SecretSharingError::SecretTooLarge {
+                prime: self.prime.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    // enough shares and no x coordinate twice, otherwise interpolation divides by zero. indices
+    // are reduced mod prime, so distinct indices that land on the same field element count twice
+    pub(crate) fn check_shares(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<(), SecretSharingError> {
+        check_shares_for(shares, self.threshold)?;
+        check_congruent_indices(shares, &self.prime)
+    }
+
+    // how many more shares a collector still has to gather before reconstruct can succeed
+    pub fn shares_needed(&self, collected: usize) -> usize {
+        self.threshold.saturating_sub(collected)
+    }
+
+    // whether collected shares are enough to reconstruct
+    pub fn can_reconstruct(&self, collected: usize) -> bool {
+        self.shares_needed(collected) == 0
+    }
+
+    pub fn reconstruct(&self, shares: &[Share]) -> Result<BigInt, SecretSharingError> {
+        let tuples: Vec<(usize, BigInt)> = shares.iter().cloned().map(Into::into).collect();
+        self.reconstruct_tuples(&tuples)
+    }
+
+    // reconstruct from (index, value) tuples, kept for callers of the tuple api
+    pub fn reconstruct_tuples(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<BigInt, SecretSharingError> {
+        self.interpolate_at(shares, &BigInt::from(0))
+    }
+
+    // reconstruct straight from an array, map or filtered iterator of shares
+    pub fn reconstruct_iter(
+        &self,
+        shares: impl IntoIterator<Item = (usize, BigInt)>,
+    ) -> Result<BigInt, SecretSharingError> {
+        let shares: Vec<(usize, BigInt)> = shares.into_iter().collect();
+        self.reconstruct_tuples(&shares)
+    }
+
+    // f(x) at any point from the shares, e.g. for mpc protocols. the secret is f(0)
+    pub fn interpolate_at(
+        &self,
+        shares: &[(usize, BigInt)],
+        x: &BigInt,
+    ) -> Result<BigInt, SecretSharingError> {
+        let checked = self.check_shares(shares);
+        // only counts and the error, share values and the result stay out of the log
+        #[cfg(feature = "tracing")]
+        match &checked {
+            Ok(()) => tracing::debug!(shares = shares.len(), "interpolating"),
+            Err(reason) => {
+                tracing::warn!(shares = shares.len(), %reason, "reconstruction rejected")
+            }
+        }
+        checked?;
+        // all shares take part, not just the first threshold, so no share is silently ignored
+        Ok(self.interpolate_first(shares, shares.len(), x))
+    }
+
+    // reconstruct from shares with arbitrary BigInt x coordinates. like reconstruct every share
+    // is interpolated. indices that differ as integers but are congruent mod prime are the same
+    // point in the field, so reject them
+    pub fn reconstruct_bigint_x(
+        &self,
+        shares: &[(BigInt, BigInt)],
+    ) -> Result<BigInt, SecretSharingError> {
+        if shares.len() < self.threshold {
+            return Err(SecretSharingError::InsufficientShares {
+                got: shares.len(),
+                need: self.threshold,
+            });
+        }
+        let prime = &self.prime;
+        let reduced: Vec<BigInt> = shares
+            .iter()
+            .map(|(x, _)| ((x % prime) + prime) % prime)
+            .collect();
+        for (n, x) in reduced.iter().enumerate() {
+            if let Some(m) = reduced[..n].iter().position(|other| other == x) {
+                return Err(SecretSharingError::CongruentShareIndices {
+                    first: shares[m].0.clone(),
+                    second: shares[n].0.clone(),
+                });
+            }
+        }
+
         let mut secret = BigInt::from(0);
-        for i in 0..self.threshold {
+        for i in 0..shares.len() {
             let mut num = BigInt::from(1);
             let mut denom = BigInt::from(1);
-            for j in 0..self.threshold {
+            for j in 0..shares.len() {
                 if i != j {
-                    // (0-xj)
-                    num = (num * (BigInt::from(-1 * xs[j] as i64))) % &self.prime;
-                    // (xi-xj)
-                    denom = (denom * (BigInt::from(xs[i] as i64 - BigInt::from(xs[j] as i64))))
-                        % &self.prime;
+                    num = (num * -&reduced[j]) % prime;
+                    denom = (denom * (&reduced[i] - &reduced[j])) % prime;
                 }
             }
-            // (-xj)/(xi-xj)
-            secret += ((num / denom) * &ys[i]) % &self.prime;
+            let inverse = mod_inverse(&denom, prime).expect("share indices are distinct mod prime");
+            secret = (secret + num * inverse % prime * &shares[i].1) % prime;
         }
-        if secret < BigInt::from(0) {
-            secret + &self.prime
-        } else {
-            secret % &self.prime
+        Ok(((secret % prime) + prime) % prime)
+    }
+
+    // reconstruct with a threshold other than the configured one, e.g. for shares of a dealing
+    // made by a differently configured instance over the same prime
+    pub fn reconstruct_with_threshold(
+        &self,
+        shares: &[(usize, BigInt)],
+        threshold: usize,
+    ) -> Result<BigInt, SecretSharingError> {
+        if threshold == 0 {
+            return Err(SecretSharingError::ZeroThreshold);
         }
+        check_shares_for(shares, threshold)?;
+        check_congruent_indices(&shares[..threshold], &self.prime)?;
+        Ok(self.interpolate_first(shares, threshold, &BigInt::from(0)))
     }
-    pub fn reconstruct(&self, shares: &Vec<(usize, BigInt)>) -> Result<BigInt, String> {
-        if shares.len() < self.threshold {
-            return Err("Require atleast ".to_string() + &self.threshold.to_string() + " shares");
+
+    // integrity signal: the secret from the polynomial through the first threshold shares plus
+    // whether every remaining share lies on that polynomial. with exactly threshold shares
+    // there is nothing to compare and the flag is true
+    pub fn reconstruct_with_redundancy_check(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<(BigInt, bool), SecretSharingError> {
+        let polynomial = self.reconstruct_polynomial(shares)?;
+        let prime = &self.prime;
+        let agreed = shares[self.threshold..]
+            .iter()
+            .all(|(x, y)| polynomial.evaluate(*x) == ((y % prime) + prime) % prime);
+        Ok((polynomial.evaluate(0), agreed))
+    }
+
+    // shamir without commitments cannot tell a wrong share from a right one with exactly
+    // threshold shares. with at least one more, every share beyond the first threshold is
+    // checked against their polynomial, so any single bad share is caught
+    pub fn reconstruct_checked(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<BigInt, SecretSharingError> {
+        check_shares_for(shares, self.threshold + 1)?;
+        match self.reconstruct_with_redundancy_check(shares)? {
+            (secret, true) => Ok(secret),
+            (_, false) => Err(SecretSharingError::InconsistentShares),
+        }
+    }
+
+    // reconstruct and name the participants whose shares were actually interpolated
+    // (every offered share), sorted for audit logs
+    pub fn reconstruct_audited(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<(BigInt, Vec<usize>), SecretSharingError> {
+        let secret = self.reconstruct_tuples(shares)?;
+        let mut used: Vec<usize> = shares.iter().map(|(i, _)| *i).collect();
+        used.sort_unstable();
+        Ok((secret, used))
+    }
+
+    // reconstruct, but error when the result is wider than the caller expects, which is how a
+    // secret wrapped around the prime usually shows. a wrapped value is only detectable when
+    // expected_bits is below the prime's width, every value fits otherwise
+    pub fn reconstruct_expecting_bits(
+        &self,
+        shares: &[(usize, BigInt)],
+        expected_bits: u64,
+    ) -> Result<BigInt, SecretSharingError> {
+        let secret = self.reconstruct_tuples(shares)?;
+        if secret.bits() > expected_bits {
+            return Err(SecretSharingError::PossibleWraparound {
+                got_bits: secret.bits(),
+                expected_bits,
+            });
+        }
+        Ok(secret)
+    }
+
+    // pointwise sum of two sharings over the same prime and x coordinates is a sharing of the sum
+    pub fn add_shares(
+        &self,
+        a: &[(usize, BigInt)],
+        b: &[(usize, BigInt)],
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        if a.len() != b.len() || a.iter().zip(b.iter()).any(|(sa, sb)| sa.0 != sb.0) {
+            return Err(SecretSharingError::IncompatibleDealing(
+                "share indices differ",
+            ));
+        }
+        Ok(a.iter()
+            .zip(b.iter())
+            .map(|((i, ya), (_, yb))| (*i, (ya + yb) % &self.prime))
+            .collect())
+    }
+
+    // change (threshold, total_shares) for an existing secret: reconstruct it and deal a fresh
+    // sharing over the same prime. the old shares do not combine with the new ones
+    #[cfg(feature = "std")]
+    pub fn reshare(
+        &self,
+        shares: &[(usize, BigInt)],
+        new_threshold: usize,
+        new_total: usize,
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        // the prime was checked when self was built
+        let mut dealer = Self::new_unchecked(new_threshold, new_total, Some(self.prime.clone()))?;
+        let secret = self.reconstruct_tuples(shares)?;
+        dealer.generate_shares_tuples(secret)
+    }
+
+    // proactive refresh: add a random polynomial with zero constant term to every share. the
+    // secret stays the same but refreshed shares no longer combine with the old ones
+    #[cfg(feature = "std")]
+    pub fn refresh_shares(&self, shares: &[(usize, BigInt)]) -> Vec<(usize, BigInt)> {
+        let mut rng = rand::thread_rng();
+        let delta = Polynomial::random(self.threshold - 1, BigInt::from(0), &self.prime, &mut rng);
+        shares
+            .iter()
+            .map(|(i, y)| (*i, (y + delta.evaluate(*i)) % &self.prime))
+            .collect()
+    }
+
+    // bytes per block in split_bytes: the largest k with 256^k < prime, so every block fits in
+    // the field. the default prime 2^31 - 1 gives 3 byte blocks
+    pub fn chunk_size(&self) -> usize {
+        ((self.prime.bits().saturating_sub(1)) / 8) as usize
+    }
+
+    // share a byte secret block by block. the input is padded with 0x80 followed by zeros up to a
+    // whole number of blocks (ISO/IEC 7816-4), so the trailing partial block round trips exactly.
+    // every participant gets one value per block at the same index
+    #[cfg(feature = "std")]
+    pub fn split_bytes(
+        &mut self,
+        secret: &[u8],
+    ) -> Result<Vec<(usize, Vec<BigInt>)>, SecretSharingError> {
+        let chunk_size = self.chunk_size();
+        if chunk_size == 0 {
+            return Err(SecretSharingError::PrimeTooSmall {
+                prime: self.prime.clone(),
+            });
+        }
+        let mut padded = secret.to_vec();
+        padded.push(0x80);
+        padded.resize(padded.len().div_ceil(chunk_size) * chunk_size, 0);
+
+        let mut shares: Vec<(usize, Vec<BigInt>)> =
+            (1..=self.total_shares).map(|i| (i, Vec::new())).collect();
+        for block in padded.chunks(chunk_size) {
+            let block_shares =
+                self.generate_shares_tuples(BigInt::from_bytes_be(Sign::Plus, block))?;
+            for (share, (_, y)) in shares.iter_mut().zip(block_shares) {
+                share.1.push(y);
+            }
+        }
+        Ok(shares)
+    }
+
+    // reassemble a secret shared with split_bytes
+    pub fn combine_bytes(
+        &self,
+        shares: &[(usize, Vec<BigInt>)],
+    ) -> Result<Vec<u8>, SecretSharingError> {
+        let chunk_size = self.chunk_size();
+        let blocks = shares.first().map(|(_, ys)| ys.len()).unwrap_or(0);
+        if shares.iter().any(|(_, ys)| ys.len() != blocks) {
+            return Err(SecretSharingError::InvalidEncoding(
+                "shares have different block counts",
+            ));
+        }
+
+        let mut padded = Vec::with_capacity(blocks * chunk_size);
+        for b in 0..blocks {
+            let block_shares: Vec<(usize, BigInt)> =
+                shares.iter().map(|(i, ys)| (*i, ys[b].clone())).collect();
+            let (_, bytes) = self.reconstruct_tuples(&block_shares)?.to_bytes_be();
+            if bytes.len() > chunk_size {
+                return Err(SecretSharingError::InvalidEncoding(
+                    "block exceeds chunk size",
+                ));
+            }
+            // restore leading zeros dropped by the integer conversion
+            padded.resize(padded.len() + chunk_size - bytes.len(), 0);
+            padded.extend_from_slice(&bytes);
+        }
+
+        // strip the 0x80 00.. padding
+        match padded.iter().rposition(|b| *b != 0) {
+            Some(end) if padded[end] == 0x80 => {
+                padded.truncate(end);
+                Ok(padded)
+            }
+            _ => Err(SecretSharingError::InvalidEncoding(
+                "missing padding marker",
+            )),
+        }
+    }
+
+    // full lagrange interpolation of the degree t-1 polynomial through the first threshold
+    // shares, coefficients reduced mod prime. a0 is the secret
+    pub fn reconstruct_polynomial(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<Polynomial, SecretSharingError> {
+        self.check_shares(shares)?;
+        let coefficients = self.interpolate_coefficients(&shares[..self.threshold]);
+        Ok(Polynomial::new(coefficients, self.prime.clone()))
+    }
+
+    // reissue a lost share: interpolate the dealing polynomial and evaluate it at index. the
+    // value is reduced mod prime, so it equals the original share mod prime
+    pub fn reissue_share(
+        &self,
+        shares: &[(usize, BigInt)],
+        index: usize,
+    ) -> Result<(usize, BigInt), SecretSharingError> {
+        // f(0) is the secret itself
+        if index == 0 {
+            return Err(SecretSharingError::InvalidShareIndex { index });
+        }
+        let polynomial = self.reconstruct_polynomial(shares)?;
+        Ok((index, polynomial.evaluate(index) % &self.prime))
+    }
+
+    // true when every share lies on the polynomial through the first threshold shares, i.e.
+    // the whole set is a single valid sharing. too few or duplicate shares are not consistent
+    pub fn is_consistent(&self, shares: &[(usize, BigInt)]) -> bool {
+        matches!(
+            self.reconstruct_with_redundancy_check(shares),
+            Ok((_, true))
+        )
+    }
+
+    // bytes of the big-endian length header in split_bytes_with_header, enough to count up to
+    // chunk_size. the default prime gives a 1 byte header and room for a 2 byte secret
+    pub fn header_size(&self) -> usize {
+        let capacity = self.chunk_size() as u64;
+        (u64::BITS - capacity.leading_zeros()).div_ceil(8).max(1) as usize
+    }
+
+    // share a short byte secret as a single field element laid out as
+    // [length header | secret | zero padding] in chunk_size bytes, so leading zeros survive
+    #[cfg(feature = "std")]
+    pub fn split_bytes_with_header(
+        &mut self,
+        secret: &[u8],
+    ) -> Result<Vec<(usize, BigInt)>, SecretSharingError> {
+        let capacity = self.chunk_size();
+        let header_size = self.header_size();
+        if capacity <= header_size {
+            return Err(SecretSharingError::PrimeTooSmall {
+                prime: self.prime.clone(),
+            });
+        }
+        if secret.len() > capacity - header_size {
+            return Err(SecretSharingError::SecretTooLarge {
+                prime: self.prime.clone(),
+            });
+        }
+
+        let length = (secret.len() as u64).to_be_bytes();
+        let mut frame = length[length.len() - header_size..].to_vec();
+        frame.extend_from_slice(secret);
+        frame.resize(capacity, 0);
+        self.generate_shares_tuples(BigInt::from_bytes_be(Sign::Plus, &frame))
+    }
+
+    // reassemble a secret shared with split_bytes_with_header
+    pub fn combine_bytes_with_header(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<Vec<u8>, SecretSharingError> {
+        let capacity = self.chunk_size();
+        let header_size = self.header_size();
+        let (_, bytes) = self.reconstruct_tuples(shares)?.to_bytes_be();
+        if bytes.len() > capacity || capacity <= header_size {
+            return Err(SecretSharingError::InvalidEncoding(
+                "frame exceeds chunk size",
+            ));
+        }
+        // restore the leading zeros of the frame
+        let mut frame = vec![0; capacity - bytes.len()];
+        frame.extend_from_slice(&bytes);
+
+        let length = frame[..header_size]
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | *b as usize);
+        if length > capacity - header_size {
+            return Err(SecretSharingError::InvalidEncoding(
+                "length header out of range",
+            ));
+        }
+        Ok(frame[header_size..header_size + length].to_vec())
+    }
+
+    // interpolate the full polynomial through all given shares and report f(0) with its real degree.
+    // a degree below threshold - 1 means the dealer used a weaker polynomial than claimed
+    pub fn reconstruct_with_degree(
+        &self,
+        shares: &[(usize, BigInt)],
+    ) -> Result<(BigInt, usize), SecretSharingError> {
+        self.check_shares(shares)?;
+        let coefficients = self.interpolate_coefficients(shares);
+        // highest non zero coefficient decides the degree
+        let degree = coefficients
+            .iter()
+            .rposition(|c| *c != BigInt::from(0))
+            .unwrap_or(0);
+        Ok((coefficients[0].clone(), degree))
+    }
+
+    // coefficients (a0..ak-1) mod prime of the polynomial through the k given shares
+    fn interpolate_coefficients(&self, shares: &[(usize, BigInt)]) -> Vec<BigInt> {
+        let prime = &self.prime;
+        let mut coefficients = vec![BigInt::from(0); shares.len()];
+        for (i, (xi, yi)) in shares.iter().enumerate() {
+            // basis polynomial prod (x-xj) and its value prod (xi-xj) at xi
+            let mut basis = vec![BigInt::from(1)];
+            let mut denom = BigInt::from(1);
+            for (j, (xj, _)) in shares.iter().enumerate() {
+                if i != j {
+                    let xj = BigInt::from(*xj);
+                    let mut next = vec![BigInt::from(0); basis.len() + 1];
+                    for (k, b) in basis.iter().enumerate() {
+                        next[k + 1] += b;
+                        next[k] -= b * &xj;
+                    }
+                    basis = next.into_iter().map(|c| c % prime).collect();
+                    denom = (denom * (BigInt::from(*xi) - xj)) % prime;
+                }
+            }
+            let inverse = mod_inverse(&denom, prime).expect("share indices must be distinct");
+            let scale = (yi * inverse) % prime;
+            for (k, b) in basis.iter().enumerate() {
+                coefficients[k] = (&coefficients[k] + b * &scale) % prime;
+            }
+        }
+        coefficients
+            .into_iter()
+            .map(|c| ((c % prime) + prime) % prime)
+            .collect()
+    }
+}
+
+// at least need shares and no x coordinate twice
+pub(crate) fn check_shares_for(
+    shares: &[(usize, BigInt)],
+    need: usize,
+) -> Result<(), SecretSharingError> {
+    if shares.len() < need {
+        return Err(SecretSharingError::InsufficientShares {
+            got: shares.len(),
+            need,
+        });
+    }
+    for (n, (index, _)) in shares.iter().enumerate() {
+        if shares[..n].iter().any(|(other, _)| other == index) {
+            return Err(SecretSharingError::DuplicateShareIndex { index: *index });
+        }
+    }
+    Ok(())
+}
+
+// indices are reduced mod prime before interpolation, so distinct indices that land on the
+// same field element (or one index given twice) would divide by zero
+pub(crate) fn check_congruent_indices(
+    shares: &[(usize, BigInt)],
+    prime: &BigInt,
+) -> Result<(), SecretSharingError> {
+    let reduced: Vec<BigInt> = shares
+        .iter()
+        .map(|(index, _)| BigInt::from(*index) % prime)
+        .collect();
+    for (n, x) in reduced.iter().enumerate() {
+        if let Some(m) = reduced[..n].iter().position(|other| other == x) {
+            return Err(SecretSharingError::CongruentShareIndices {
+                first: BigInt::from(shares[m].0),
+                second: BigInt::from(shares[n].0),
+            });
         }
-        // unzip x values and corresponding y values
-        let (xs, ys) = shares.iter().cloned().unzip();
-        let recovered_secret = self.lagrange_interpolation(xs, ys);
-        Ok(recovered_secret)
     }
+    Ok(())
+}
+
+// sha256 over every (index, value) pair, interpreted as a non-negative integer
+fn canary_value(shares: &[(usize, BigInt)]) -> BigInt {
+    let mut hasher = Sha256::new();
+    for (i, y) in shares.iter() {
+        hasher.update((*i as u64).to_be_bytes());
+        hasher.update(y.to_signed_bytes_be());
+    }
+    BigInt::from_bytes_be(Sign::Plus, &hasher.finalize())
+}
+
+// DEFAULT_PRIME_U32 as a BigInt, what new and FeldmanVSS::new use without an explicit prime
+pub fn default_prime() -> BigInt {
+    BigInt::from(DEFAULT_PRIME_U32)
+}
+
+// modular multiplicative inverse of a mod p using the extended euclidean algorithm
+pub fn mod_inverse(a: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let zero = BigInt::from(0);
+    let (mut old_r, mut r) = (((a % p) + p) % p, p.clone());
+    let (mut old_s, mut s) = (BigInt::from(1), zero.clone());
+    while r != zero {
+        let quotient = &old_r / &r;
+        let next_r = &old_r - &quotient * &r;
+        old_r = core::mem::replace(&mut r, next_r);
+        let next_s = &old_s - &quotient * &s;
+        old_s = core::mem::replace(&mut s, next_s);
+    }
+    // gcd(a, p) has to be 1 for the inverse to exist
+    if old_r != BigInt::from(1) {
+        return None;
+    }
+    Some(((old_s % p) + p) % p)
+}
+
+// equality without an early exit on the first differing byte. both values are encoded as a
+// sign byte plus the magnitude left padded to the wider of the two, so only that width (the
+// prime's for reduced field elements) is visible in the timing
+pub fn constant_time_eq(a: &BigInt, b: &BigInt) -> bool {
+    let (sign_a, bytes_a) = a.to_bytes_be();
+    let (sign_b, bytes_b) = b.to_bytes_be();
+    let width = bytes_a.len().max(bytes_b.len());
+    let encode = |sign: Sign, bytes: &[u8]| {
+        let mut encoded = vec![0; width + 1 - bytes.len()];
+        encoded[0] = sign as u8;
+        encoded.extend_from_slice(bytes);
+        encoded
+    };
+    encode(sign_a, &bytes_a)
+        .ct_eq(&encode(sign_b, &bytes_b))
+        .into()
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::algorithms::feldman_vss::FeldmanVSS;
+    use crate::algorithms::polynomial::Polynomial;
+    use crate::algorithms::shamir_secret_sharing::{
+        constant_time_eq, default_prime, mod_inverse, ShamirBuilder, ShamirSecretSharing,
+        ShareLimits,
+    };
+    use crate::error::SecretSharingError;
     use num_bigint::BigInt;
 
     // Helper function to avoid code duplication in generating shares and validating counts
@@ -139,7 +1084,7 @@ mod tests {
         secret: BigInt,
     ) -> Vec<(usize, BigInt)> {
         let mut shamir = ShamirSecretSharing::new(threshold, total_shares, None).unwrap();
-        let shares = shamir.generate_shares(secret).unwrap();
+        let shares = shamir.generate_shares_tuples(secret).unwrap();
         assert_eq!(
             shares.len(),
             total_shares,
@@ -249,7 +1194,7 @@ mod tests {
         // Reconstruct secret using the threshold number of shares
         let reconstructed_secret = {
             let shamir = ShamirSecretSharing::new(threshold, total_shares, None).unwrap();
-            shamir.reconstruct(&shares[0..threshold].to_vec()).unwrap()
+            shamir.reconstruct_tuples(&shares[0..threshold]).unwrap()
         };
 
         assert_eq!(
@@ -257,4 +1202,1296 @@ mod tests {
             "Reconstructed secret should match the original secret"
         );
     }
+
+    #[test]
+    fn nonzero_shares_tiny_prime_test() {
+        // with p = 11 roughly half of all random polynomials hit zero at some x in 1..=5
+        let mut shamir = ShamirSecretSharing::new(2, 5, Some(BigInt::from(11))).unwrap();
+        for _ in 0..50 {
+            let shares = shamir.generate_shares_nonzero(BigInt::from(3)).unwrap();
+            for (_, y) in shares.iter() {
+                assert!(
+                    *y > BigInt::from(0) && *y < BigInt::from(11),
+                    "Share value should be in [1, prime)"
+                );
+            }
+            assert_eq!(
+                shamir.reconstruct_tuples(&shares).unwrap(),
+                BigInt::from(3),
+                "Reduced shares should still reconstruct the secret"
+            );
+        }
+    }
+
+    #[test]
+    fn nonzero_shares_unsatisfiable_test() {
+        // a constant zero polynomial can never produce a nonzero share
+        let mut shamir = ShamirSecretSharing::new(1, 3, Some(BigInt::from(11))).unwrap();
+        let result = shamir.generate_shares_nonzero(BigInt::from(0));
+        assert!(
+            result.is_err(),
+            "Expected an error when nonzero shares are impossible"
+        );
+    }
+
+    #[test]
+    fn mod_inverse_test() {
+        let prime = default_prime();
+        let inverse = mod_inverse(&BigInt::from(3), &prime).unwrap();
+        assert_eq!(
+            (inverse * BigInt::from(3)) % &prime,
+            BigInt::from(1),
+            "a * a^-1 should be 1 mod prime"
+        );
+        assert_eq!(
+            mod_inverse(&BigInt::from(-1), &prime).unwrap(),
+            &prime - 1,
+            "Inverse of -1 should be prime - 1"
+        );
+        assert!(
+            mod_inverse(&BigInt::from(4), &BigInt::from(100)).is_none(),
+            "No inverse should exist when gcd(a, p) != 1"
+        );
+    }
+
+    #[test]
+    fn reconstruct_non_contiguous_indices_test() {
+        // lagrange coefficients for x = 1, 2, 4 are fractions (e.g. 8/3), which integer division truncates
+        let threshold = 3;
+        let total_shares = 5;
+        let secret = BigInt::from(1234);
+
+        let shares = generate_shares_and_validate(threshold, total_shares, secret.clone());
+        let subset = vec![shares[0].clone(), shares[1].clone(), shares[3].clone()];
+
+        let shamir = ShamirSecretSharing::new(threshold, total_shares, None).unwrap();
+        assert_eq!(
+            shamir.reconstruct_tuples(&subset).unwrap(),
+            secret,
+            "Reconstructed secret should match the original secret"
+        );
+    }
+
+    #[test]
+    fn canary_detects_tampering_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir
+            .generate_shares_with_canary(BigInt::from(1234))
+            .unwrap();
+        assert_eq!(shares.len(), 6, "Canary should be appended to the shares");
+        assert!(
+            ShamirSecretSharing::verify_canary(&shares),
+            "Untouched shares should pass the canary check"
+        );
+
+        let mut tampered = shares.clone();
+        tampered[2].1 += 1;
+        assert!(
+            !ShamirSecretSharing::verify_canary(&tampered),
+            "Modified share should fail the canary check"
+        );
+
+        // the canary is not a polynomial point, reconstruction uses the real shares only
+        assert_eq!(
+            shamir.reconstruct_tuples(&shares[..5]).unwrap(),
+            BigInt::from(1234),
+            "Shares without the canary should reconstruct the secret"
+        );
+    }
+
+    #[test]
+    fn error_variants_test() {
+        assert_eq!(
+            ShamirSecretSharing::new(6, 5, None).unwrap_err(),
+            SecretSharingError::ThresholdExceedsShares {
+                threshold: 6,
+                total_shares: 5
+            },
+            "Threshold above total shares should be reported as such"
+        );
+
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        assert!(
+            matches!(
+                shamir.generate_shares(BigInt::from(9100932139u64)),
+                Err(SecretSharingError::SecretTooLarge { .. })
+            ),
+            "Secret above the prime should be reported as such"
+        );
+
+        let shares = shamir.generate_shares(BigInt::from(1234)).unwrap();
+        let err = shamir.reconstruct(&shares[0..2]).unwrap_err();
+        assert_eq!(
+            err,
+            SecretSharingError::InsufficientShares { got: 2, need: 3 },
+            "Too few shares should report how many were given and needed"
+        );
+        assert_eq!(err.to_string(), "require at least 3 shares, got 2");
+    }
+
+    #[test]
+    fn reconstruct_with_degree_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let (secret, degree) = shamir.reconstruct_with_degree(&shares).unwrap();
+        assert_eq!(secret, BigInt::from(1234), "Secret should be recovered");
+        assert_eq!(
+            degree, 2,
+            "Random polynomial should have degree threshold - 1"
+        );
+
+        // dealer whose top coefficient happens to be zero
+        shamir.polynomial = Polynomial::new(
+            vec![BigInt::from(1234), BigInt::from(56), BigInt::from(0)],
+            shamir.prime.clone(),
+        );
+        let weak_shares: Vec<(usize, BigInt)> = (1..=5)
+            .map(|i| (i, shamir.polynomial.evaluate(i)))
+            .collect();
+        let (secret, degree) = shamir.reconstruct_with_degree(&weak_shares).unwrap();
+        assert_eq!(secret, BigInt::from(1234), "Secret should be recovered");
+        assert_eq!(degree, 1, "Zero top coefficient should lower the degree");
+    }
+
+    #[test]
+    fn split_bytes_round_trip_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        assert_eq!(
+            shamir.chunk_size(),
+            3,
+            "Default prime should hold 3 byte blocks"
+        );
+
+        // 37 bytes is not a multiple of the chunk size and starts with zero bytes
+        let mut secret: Vec<u8> = vec![0, 0];
+        secret.extend((0..35).map(|b| (b * 7) as u8));
+        let shares = shamir.split_bytes(&secret).unwrap();
+        assert_eq!(shares.len(), 5, "Every participant should get a share");
+
+        let recovered = shamir.combine_bytes(&shares[1..4]).unwrap();
+        assert_eq!(recovered, secret, "Recovered bytes should match exactly");
+    }
+
+    #[test]
+    fn split_bytes_empty_and_trailing_zero_test() {
+        let mut shamir = ShamirSecretSharing::new(2, 3, None).unwrap();
+        for secret in [vec![], vec![0u8], vec![1, 2, 3, 0, 0]] {
+            let shares = shamir.split_bytes(&secret).unwrap();
+            assert_eq!(
+                shamir.combine_bytes(&shares).unwrap(),
+                secret,
+                "Recovered bytes should match exactly"
+            );
+        }
+    }
+
+    #[test]
+    fn non_prime_modulus_test() {
+        assert_eq!(
+            ShamirSecretSharing::new(2, 5, Some(BigInt::from(100))).unwrap_err(),
+            SecretSharingError::NonPrimeModulus {
+                modulus: BigInt::from(100)
+            },
+            "Composite modulus should be rejected"
+        );
+        assert!(
+            ShamirSecretSharing::new_unchecked(2, 5, Some(BigInt::from(100))).is_ok(),
+            "Unchecked constructor should skip the primality test"
+        );
+    }
+
+    #[test]
+    fn reconstruct_expecting_bits_test() {
+        let mut shamir = ShamirSecretSharing::new(2, 3, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            shamir.reconstruct_expecting_bits(&shares, 16).unwrap(),
+            BigInt::from(1234),
+            "Secret within the expected width should reconstruct"
+        );
+
+        // an honest secret as wide as the prime is fine when the caller allows that width
+        let full_width: BigInt = &shamir.prime - 1;
+        let shares = shamir.generate_shares_tuples(full_width.clone()).unwrap();
+        assert_eq!(
+            shamir.reconstruct_expecting_bits(&shares, 31).unwrap(),
+            full_width,
+            "A full width secret should be accepted"
+        );
+
+        // a 40 bit secret smuggled past the size check wraps around the 31 bit prime, a
+        // caller expecting 24 bit secrets notices the full width result
+        let secret = BigInt::from(0xab_cdef_0123u64);
+        shamir.polynomial =
+            Polynomial::new(vec![secret.clone(), BigInt::from(77)], shamir.prime.clone());
+        let wrapped: Vec<(usize, BigInt)> = (1..=3)
+            .map(|i| (i, shamir.polynomial.evaluate(i)))
+            .collect();
+        assert_ne!(
+            shamir.reconstruct_tuples(&wrapped).unwrap(),
+            secret,
+            "Plain reconstruction silently returns secret mod prime"
+        );
+        assert!(
+            matches!(
+                shamir.reconstruct_expecting_bits(&wrapped, 24),
+                Err(SecretSharingError::PossibleWraparound { .. })
+            ),
+            "Wrapped secret should be detected"
+        );
+    }
+
+    #[test]
+    fn duplicate_share_index_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let duplicated = vec![
+            (1, shares[0].1.clone()),
+            (1, shares[1].1.clone()),
+            (3, shares[2].1.clone()),
+        ];
+        assert_eq!(
+            shamir.reconstruct_tuples(&duplicated),
+            Err(SecretSharingError::DuplicateShareIndex { index: 1 }),
+            "Duplicate index should be a clean error"
+        );
+    }
+
+    #[test]
+    fn custom_indices_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let indices = [10, 20, 30, 40, 50];
+        let shares = shamir
+            .generate_shares_with_indices(BigInt::from(1234), &indices)
+            .unwrap();
+        let xs: Vec<usize> = shares.iter().map(|(x, _)| *x).collect();
+        assert_eq!(xs, indices, "Shares should use the supplied indices");
+
+        let subset = vec![shares[4].clone(), shares[1].clone(), shares[2].clone()];
+        assert_eq!(
+            shamir.reconstruct_tuples(&subset).unwrap(),
+            BigInt::from(1234),
+            "Any threshold subset should reconstruct the secret"
+        );
+    }
+
+    #[test]
+    fn custom_indices_validation_test() {
+        let mut shamir = ShamirSecretSharing::new(2, 3, None).unwrap();
+        assert_eq!(
+            shamir.generate_shares_with_indices(BigInt::from(1), &[0, 1, 2]),
+            Err(SecretSharingError::InvalidShareIndex { index: 0 }),
+            "Index zero would reveal the secret"
+        );
+        assert_eq!(
+            shamir.generate_shares_with_indices(BigInt::from(1), &[7, 8, 7]),
+            Err(SecretSharingError::DuplicateShareIndex { index: 7 }),
+            "Indices have to be distinct"
+        );
+    }
+
+    #[test]
+    fn zero_threshold_test() {
+        assert_eq!(
+            ShamirSecretSharing::new(0, 5, None).unwrap_err(),
+            SecretSharingError::ZeroThreshold,
+            "Zero threshold should be a clean error"
+        );
+    }
+
+    #[test]
+    fn add_shares_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let a = shamir.generate_shares_tuples(BigInt::from(100)).unwrap();
+        let b = shamir.generate_shares_tuples(BigInt::from(200)).unwrap();
+
+        let sum = shamir.add_shares(&a, &b).unwrap();
+        assert_eq!(
+            shamir.reconstruct_tuples(&sum[2..5]).unwrap(),
+            BigInt::from(300),
+            "Sum of share sets should reconstruct the sum of the secrets"
+        );
+
+        assert!(
+            shamir.add_shares(&a[0..3], &b[1..4]).is_err(),
+            "Mismatched indices should be rejected"
+        );
+    }
+
+    #[test]
+    fn reconstruct_audited_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let offered = vec![
+            shares[3].clone(),
+            shares[0].clone(),
+            shares[4].clone(),
+            shares[1].clone(),
+        ];
+
+        let (secret, used) = shamir.reconstruct_audited(&offered).unwrap();
+        assert_eq!(secret, BigInt::from(1234));
+        assert_eq!(
+            used,
+            vec![1, 2, 4, 5],
+            "Should name the interpolated participants"
+        );
+    }
+
+    #[test]
+    fn refresh_shares_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let old = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let new = shamir.refresh_shares(&old);
+        assert_ne!(old, new, "Refreshed shares should differ from the old ones");
+
+        assert_eq!(
+            shamir.reconstruct_tuples(&new[2..5]).unwrap(),
+            BigInt::from(1234),
+            "Refreshed shares should reconstruct the same secret"
+        );
+
+        let mixed = vec![old[0].clone(), old[1].clone(), new[2].clone()];
+        assert_ne!(
+            shamir.reconstruct_tuples(&mixed).unwrap(),
+            BigInt::from(1234),
+            "Mixing old and refreshed shares should not give the secret"
+        );
+    }
+
+    #[test]
+    fn reconstruct_polynomial_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+
+        let polynomial = shamir.reconstruct_polynomial(&shares[0..3]).unwrap();
+        assert_eq!(polynomial.degree(), 2, "Polynomial should have degree t-1");
+        assert_eq!(
+            polynomial.coefficients, shamir.polynomial.coefficients,
+            "Interpolated coefficients should match the dealer's"
+        );
+
+        let (x, y) = &shares[3];
+        assert_eq!(
+            polynomial.evaluate(*x) % &shamir.prime,
+            y % &shamir.prime,
+            "Evaluating at the 4th share's x should give its y"
+        );
+    }
+
+    #[test]
+    fn seeded_shares_test() {
+        let seed = [7u8; 32];
+        let mut shamir = ShamirSecretSharing::new(3, 12, None).unwrap();
+        let first = shamir
+            .generate_shares_seeded(BigInt::from(1234), seed)
+            .unwrap();
+        let second = shamir
+            .generate_shares_seeded(BigInt::from(1234), seed)
+            .unwrap();
+        let to_bytes = |shares: &Vec<(usize, BigInt)>| -> Vec<Vec<u8>> {
+            shares.iter().map(|(_, y)| y.to_signed_bytes_be()).collect()
+        };
+        assert_eq!(
+            to_bytes(&first),
+            to_bytes(&second),
+            "Same seed should give byte for byte identical shares"
+        );
+
+        let other = shamir
+            .generate_shares_seeded(BigInt::from(1234), [8u8; 32])
+            .unwrap();
+        assert_ne!(first, other, "Different seeds should give different shares");
+    }
+
+    #[test]
+    fn split_bytes_with_header_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        assert_eq!(
+            shamir.header_size(),
+            1,
+            "Default prime should use a 1 byte header"
+        );
+        for secret in [vec![], vec![0u8], vec![0, 0], vec![0, 7]] {
+            let shares = shamir.split_bytes_with_header(&secret).unwrap();
+            assert_eq!(
+                shamir.combine_bytes_with_header(&shares[1..4]).unwrap(),
+                secret,
+                "Secret with leading zeros should be recovered exactly"
+            );
+        }
+        assert!(
+            shamir.split_bytes_with_header(&[1, 2, 3]).is_err(),
+            "Secret longer than the field allows should be rejected"
+        );
+    }
+
+    #[test]
+    fn split_bytes_with_header_large_prime_test() {
+        let prime = BigInt::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .unwrap();
+        let mut shamir = ShamirSecretSharing::new(2, 3, Some(prime)).unwrap();
+        let secret = vec![0, 0, 0, 0x13, 0x37, 0, 0x42];
+        let shares = shamir.split_bytes_with_header(&secret).unwrap();
+        assert_eq!(
+            shamir.combine_bytes_with_header(&shares).unwrap(),
+            secret,
+            "Secret with leading zeros should be recovered exactly"
+        );
+    }
+
+    #[test]
+    fn reconstruct_bigint_x_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let big_x: Vec<(BigInt, BigInt)> = shares
+            .iter()
+            .map(|(x, y)| (BigInt::from(*x), y.clone()))
+            .collect();
+        assert_eq!(
+            shamir.reconstruct_bigint_x(&big_x[1..4]).unwrap(),
+            BigInt::from(1234),
+            "BigInt x coordinates should reconstruct like usize ones"
+        );
+
+        // a share past the threshold takes part too, so corrupting it shows up the same way
+        let mut corrupted = shares.clone();
+        corrupted[4].1 += 1;
+        let corrupted_big_x: Vec<(BigInt, BigInt)> = corrupted
+            .iter()
+            .map(|(x, y)| (BigInt::from(*x), y.clone()))
+            .collect();
+        assert_eq!(
+            shamir.reconstruct_bigint_x(&corrupted_big_x).unwrap(),
+            shamir.reconstruct_tuples(&corrupted).unwrap(),
+            "Every share should be interpolated, as in reconstruct"
+        );
+    }
+
+    #[test]
+    fn reconstruct_bigint_x_congruent_indices_test() {
+        let shamir = ShamirSecretSharing::new(2, 3, Some(BigInt::from(11))).unwrap();
+        // 3 and 14 are the same point mod 11
+        let shares = vec![
+            (BigInt::from(3), BigInt::from(5)),
+            (BigInt::from(14), BigInt::from(7)),
+        ];
+        assert_eq!(
+            shamir.reconstruct_bigint_x(&shares),
+            Err(SecretSharingError::CongruentShareIndices {
+                first: BigInt::from(3),
+                second: BigInt::from(14)
+            }),
+            "Indices congruent mod prime should be rejected"
+        );
+    }
+
+    #[test]
+    fn default_limits_test() {
+        assert_eq!(
+            ShamirSecretSharing::new(2000, 2000, None).unwrap_err(),
+            SecretSharingError::ThresholdAboveLimit {
+                threshold: 2000,
+                max: 1024
+            },
+            "Threshold above the default limit should be rejected"
+        );
+        assert_eq!(
+            ShamirSecretSharing::new(3, 1_000_000, None).unwrap_err(),
+            SecretSharingError::TotalSharesAboveLimit {
+                total_shares: 1_000_000,
+                max: 4096
+            },
+            "Share count above the default limit should be rejected"
+        );
+        assert!(ShamirSecretSharing::new(1024, 4096, None).is_ok());
+    }
+
+    #[test]
+    fn raised_limits_test() {
+        let limits = ShareLimits::default()
+            .max_threshold(2000)
+            .max_total_shares(5000);
+        let shamir = ShamirSecretSharing::new_with_limits(2000, 5000, None, limits).unwrap();
+        assert_eq!(shamir.threshold, 2000);
+        assert_eq!(shamir.total_shares, 5000);
+    }
+
+    #[test]
+    fn reconstruct_with_threshold_test() {
+        let mut dealer = ShamirSecretSharing::new(4, 6, None).unwrap();
+        let shares = dealer.generate_shares_tuples(BigInt::from(1234)).unwrap();
+
+        let client = ShamirSecretSharing::new(2, 3, None).unwrap();
+        assert_ne!(
+            client.reconstruct_tuples(&shares[..2]).unwrap(),
+            BigInt::from(1234),
+            "The client's own threshold is too low for this dealing"
+        );
+        assert_eq!(
+            client.reconstruct_with_threshold(&shares[1..5], 4).unwrap(),
+            BigInt::from(1234),
+            "Overridden threshold should interpolate the right degree"
+        );
+        assert_eq!(
+            client.reconstruct_with_threshold(&shares[..3], 4),
+            Err(SecretSharingError::InsufficientShares { got: 3, need: 4 })
+        );
+    }
+
+    #[test]
+    fn for_secret_test() {
+        let secret = BigInt::parse_bytes(b"1234567890123456789012345678901234567890", 10).unwrap();
+        let mut shamir = ShamirSecretSharing::for_secret(3, 5, &secret).unwrap();
+        assert!(shamir.prime > secret, "Prime should exceed the secret");
+        let shares = shamir.generate_shares(secret.clone()).unwrap();
+        assert_eq!(
+            shamir.reconstruct(&shares[2..]).unwrap(),
+            secret,
+            "40 digit secret should round trip"
+        );
+
+        let small = ShamirSecretSharing::for_secret(3, 5, &BigInt::from(1234)).unwrap();
+        assert_eq!(
+            small.prime,
+            default_prime(),
+            "Small secrets keep the default prime"
+        );
+    }
+
+    #[test]
+    fn clear_coefficients_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares(BigInt::from(1234)).unwrap();
+        assert_eq!(shamir.polynomial.coefficients.len(), 3);
+
+        shamir.clear_coefficients();
+        assert!(
+            shamir.polynomial.coefficients.is_empty(),
+            "Coefficients should be scrubbed"
+        );
+        assert_eq!(
+            shamir.reconstruct(&shares[..3]).unwrap(),
+            BigInt::from(1234),
+            "Shares stay usable after clearing"
+        );
+    }
+
+    #[test]
+    fn builder_test() {
+        let shamir = ShamirBuilder::new()
+            .threshold(3)
+            .total_shares(5)
+            .prime(BigInt::from(7919))
+            .build()
+            .unwrap();
+        assert_eq!(shamir.threshold, 3);
+        assert_eq!(shamir.total_shares, 5);
+        assert_eq!(shamir.prime, BigInt::from(7919));
+
+        assert_eq!(
+            ShamirBuilder::new()
+                .threshold(6)
+                .total_shares(5)
+                .build()
+                .unwrap_err(),
+            SecretSharingError::ThresholdExceedsShares {
+                threshold: 6,
+                total_shares: 5
+            },
+            "Builder should apply the constructor's validation"
+        );
+        assert_eq!(
+            ShamirBuilder::new().total_shares(5).build().unwrap_err(),
+            SecretSharingError::ZeroThreshold,
+            "Unset threshold should fail validation"
+        );
+    }
+
+    #[test]
+    fn precomputed_index_powers_test() {
+        let mut uncached = ShamirSecretSharing::new(4, 12, None).unwrap();
+        let mut cached = ShamirSecretSharing::new(4, 12, None).unwrap();
+        cached.precompute_index_powers();
+        for seed in 0..5u8 {
+            assert_eq!(
+                cached
+                    .generate_shares_seeded(BigInt::from(1234), [seed; 32])
+                    .unwrap(),
+                uncached
+                    .generate_shares_seeded(BigInt::from(1234), [seed; 32])
+                    .unwrap(),
+                "Cached and uncached generation should give identical shares"
+            );
+        }
+
+        // 200^9 is far above 2^31 - 1, the cache still only holds field elements
+        let mut wide = ShamirSecretSharing::new(10, 200, None).unwrap();
+        wide.precompute_index_powers();
+        let powers = wide.index_powers.as_ref().unwrap();
+        assert!(powers.iter().flatten().all(|power| *power < wide.prime));
+        assert_eq!(
+            powers[199][9],
+            BigInt::from(200).modpow(&BigInt::from(9), &wide.prime)
+        );
+        let mut plain = ShamirSecretSharing::new(10, 200, None).unwrap();
+        assert_eq!(
+            wide.generate_shares_seeded(BigInt::from(1234), [9; 32])
+                .unwrap(),
+            plain
+                .generate_shares_seeded(BigInt::from(1234), [9; 32])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn borrowed_reconstruct_test() {
+        let prime = BigInt::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .unwrap();
+        let mut shamir = ShamirSecretSharing::new(5, 8, Some(prime)).unwrap();
+        let secret = BigInt::from(1234567890123456789u64);
+        let shares = shamir.generate_shares_tuples(secret.clone()).unwrap();
+
+        let (xs, ys): (Vec<usize>, Vec<BigInt>) = shares[2..].iter().cloned().unzip();
+        assert_eq!(
+            shamir.reconstruct_tuples(&shares[2..]).unwrap(),
+            shamir.lagrange_interpolation(xs, ys).unwrap(),
+            "Borrowing and owning paths should agree"
+        );
+        assert_eq!(shamir.reconstruct_tuples(&shares[2..]).unwrap(), secret);
+    }
+
+    #[test]
+    fn prime_total_shares_boundary_test() {
+        // prime == total_shares would make index total_shares collide with 0
+        assert_eq!(
+            ShamirSecretSharing::new(2, 7, Some(BigInt::from(7))).unwrap_err(),
+            SecretSharingError::SharesExceedPrime {
+                total_shares: 7,
+                prime: BigInt::from(7)
+            },
+            "prime == total_shares should be rejected"
+        );
+        assert!(
+            ShamirSecretSharing::new(2, 8, Some(BigInt::from(7))).is_err(),
+            "prime < total_shares should be rejected"
+        );
+
+        // prime == total_shares + 1 uses every non zero element once and still works
+        let mut shamir = ShamirSecretSharing::new(3, 6, Some(BigInt::from(7))).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(5)).unwrap();
+        assert_eq!(shares.last().unwrap().0, 6);
+        assert_eq!(
+            shamir.reconstruct_tuples(&shares[3..]).unwrap(),
+            BigInt::from(5),
+            "Highest indices should still reconstruct"
+        );
+    }
+
+    #[test]
+    fn is_consistent_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 6, None).unwrap();
+        let mut shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert!(
+            shamir.is_consistent(&shares),
+            "Honest sharing should be consistent"
+        );
+
+        shares[4].1 += 1;
+        assert!(
+            !shamir.is_consistent(&shares),
+            "One inconsistent share should be detected"
+        );
+        assert!(!shamir.is_consistent(&shares[..2]), "Too few shares");
+    }
+
+    #[test]
+    fn serde_excludes_coefficients_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let secret = BigInt::from(987654321);
+        shamir.generate_shares(secret.clone()).unwrap();
+
+        let json = serde_json::to_string(&shamir).unwrap();
+        assert!(
+            !json.contains("coefficients") && !json.contains("polynomial"),
+            "Serialized state should not mention coefficients: {}",
+            json
+        );
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value.as_object().unwrap().len(),
+            3,
+            "Only threshold, total_shares and prime should be written"
+        );
+
+        let restored: ShamirSecretSharing = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.threshold, 3);
+        assert_eq!(restored.total_shares, 5);
+        assert_eq!(restored.prime, shamir.prime);
+        assert!(restored.polynomial.coefficients.is_empty());
+
+        let invalid = r#"{"threshold":6,"total_shares":5,"prime":[1,[2147483647]]}"#;
+        assert!(
+            serde_json::from_str::<ShamirSecretSharing>(invalid).is_err(),
+            "Invalid persisted parameters should be rejected"
+        );
+    }
+
+    #[test]
+    fn reconstruct_uses_all_shares_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let mut shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            shamir.reconstruct_tuples(&shares).unwrap(),
+            BigInt::from(1234),
+            "All five consistent shares should give the secret"
+        );
+
+        // a bad share past the first threshold is no longer ignored
+        shares[4].1 += 1;
+        assert_ne!(
+            shamir.reconstruct_tuples(&shares).unwrap(),
+            BigInt::from(1234),
+            "A corrupted extra share should change the result"
+        );
+        assert!(!shamir.is_consistent(&shares));
+    }
+
+    #[test]
+    fn verify_constant_term_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let secret = BigInt::from(1234);
+        assert!(!shamir.verify_constant_term(&secret), "Nothing dealt yet");
+
+        shamir.generate_shares(secret.clone()).unwrap();
+        assert!(shamir.verify_constant_term(&secret));
+        assert!(!shamir.verify_constant_term(&BigInt::from(1235)));
+
+        shamir.polynomial.coefficients[0] += 1;
+        assert!(
+            !shamir.verify_constant_term(&secret),
+            "An overwritten constant term should be detected"
+        );
+    }
+
+    #[test]
+    fn reconstruct_with_redundancy_check_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let mut shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            shamir.reconstruct_with_redundancy_check(&shares).unwrap(),
+            (BigInt::from(1234), true),
+            "Clean shares should agree"
+        );
+        assert_eq!(
+            shamir
+                .reconstruct_with_redundancy_check(&shares[..3])
+                .unwrap(),
+            (BigInt::from(1234), true)
+        );
+
+        shares[4].1 += 1;
+        assert_eq!(
+            shamir.reconstruct_with_redundancy_check(&shares).unwrap(),
+            (BigInt::from(1234), false),
+            "The clean minimal set still gives the secret but the check should fail"
+        );
+
+        // t = 3 with 7 shares: share 4 is neither in the first nor in the last three
+        let mut shamir = ShamirSecretSharing::new(3, 7, None).unwrap();
+        let mut shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        shares[3].1 += 1;
+        assert_eq!(
+            shamir.reconstruct_with_redundancy_check(&shares).unwrap(),
+            (BigInt::from(1234), false),
+            "A corrupted middle share should be detected"
+        );
+        assert_eq!(
+            shamir.reconstruct_checked(&shares),
+            Err(SecretSharingError::InconsistentShares)
+        );
+    }
+
+    #[test]
+    fn threshold_fifty_test() {
+        let mut shamir = ShamirSecretSharing::new(50, 60, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert!(
+            shares.iter().all(|(_, y)| *y < shamir.prime),
+            "A degree 49 polynomial should still give shares below the prime"
+        );
+        assert_eq!(
+            shamir.reconstruct_tuples(&shares[5..55]).unwrap(),
+            BigInt::from(1234)
+        );
+    }
+
+    #[test]
+    fn reissue_share_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let lost = shares[2].clone();
+        let others: Vec<(usize, BigInt)> =
+            shares.iter().filter(|(i, _)| *i != 3).cloned().collect();
+
+        let reissued = shamir.reissue_share(&others, 3).unwrap();
+        assert_eq!(reissued, lost, "Reissued share should match the lost one");
+        assert_eq!(
+            shamir
+                .reconstruct_tuples(&[reissued, others[0].clone(), others[3].clone()])
+                .unwrap(),
+            BigInt::from(1234)
+        );
+        assert_eq!(
+            shamir.reissue_share(&others, 0),
+            Err(SecretSharingError::InvalidShareIndex { index: 0 })
+        );
+    }
+
+    #[test]
+    fn negative_secret_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        assert_eq!(
+            shamir.generate_shares(BigInt::from(-5)),
+            Err(SecretSharingError::NegativeSecret)
+        );
+        assert_eq!(
+            shamir.generate_shares_with_indices(BigInt::from(-5), &[1, 2, 3]),
+            Err(SecretSharingError::NegativeSecret)
+        );
+        assert!(shamir.generate_shares(BigInt::from(0)).is_ok());
+    }
+
+    #[test]
+    fn debug_redacts_secret_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        shamir.generate_shares(BigInt::from(987654321)).unwrap();
+        let printed = format!("{:?}", shamir);
+        assert!(
+            !printed.contains("987654321"),
+            "Debug output should not contain the secret: {}",
+            printed
+        );
+        assert!(printed.contains("coefficients: [REDACTED; 3]"));
+        assert!(printed.contains("threshold: 3") && printed.contains("prime: 2147483647"));
+    }
+
+    #[test]
+    fn total_shares_within_field_test() {
+        let expected = SecretSharingError::SharesExceedPrime {
+            total_shares: 10,
+            prime: BigInt::from(7),
+        };
+        assert_eq!(
+            ShamirSecretSharing::new(3, 10, Some(BigInt::from(7))).unwrap_err(),
+            expected
+        );
+        assert_eq!(
+            ShamirSecretSharing::new_unchecked(3, 10, Some(BigInt::from(7))).unwrap_err(),
+            expected,
+            "The unchecked constructor should still bound total_shares"
+        );
+
+        // custom indices have to be distinct and non zero mod prime as well
+        let mut shamir = ShamirSecretSharing::new(2, 3, Some(BigInt::from(7))).unwrap();
+        assert_eq!(
+            shamir.generate_shares_with_indices(BigInt::from(1), &[1, 8]),
+            Err(SecretSharingError::CongruentShareIndices {
+                first: BigInt::from(1),
+                second: BigInt::from(8)
+            })
+        );
+        assert_eq!(
+            shamir.generate_shares_with_indices(BigInt::from(1), &[14, 2]),
+            Err(SecretSharingError::InvalidShareIndex { index: 14 })
+        );
+    }
+
+    #[test]
+    fn constant_time_eq_test() {
+        use num_bigint::RandBigInt;
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let prime = default_prime();
+        for _ in 0..1000 {
+            let a = rng.gen_bigint_range(&BigInt::from(0), &prime);
+            let b = if rng.gen_bool(0.5) {
+                a.clone()
+            } else {
+                rng.gen_bigint_range(&BigInt::from(0), &prime)
+            };
+            assert_eq!(constant_time_eq(&a, &b), a == b, "{} vs {}", a, b);
+        }
+        assert!(constant_time_eq(&BigInt::from(0), &BigInt::from(0)));
+        assert!(!constant_time_eq(&BigInt::from(5), &BigInt::from(-5)));
+        assert!(!constant_time_eq(&BigInt::from(1), &BigInt::from(256)));
+    }
+
+    #[test]
+    fn interpolation_stays_in_field_test() {
+        // f(x) = 5 + 6x mod 7: shares (1, 4) and (2, 3) make every lagrange numerator and
+        // denominator negative as integers
+        let shamir = ShamirSecretSharing::new(2, 3, Some(BigInt::from(7))).unwrap();
+        assert_eq!(
+            shamir
+                .lagrange_interpolation(vec![1, 2], vec![BigInt::from(4), BigInt::from(3)])
+                .unwrap(),
+            BigInt::from(5)
+        );
+        // negative and unreduced share values land in [0, prime) as well
+        assert_eq!(
+            shamir
+                .lagrange_interpolation(vec![1, 2], vec![BigInt::from(-3), BigInt::from(17)])
+                .unwrap(),
+            BigInt::from(5)
+        );
+    }
+
+    #[test]
+    fn reshare_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let old = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let new = shamir.reshare(&old[1..4], 4, 7).unwrap();
+        assert_eq!(new.len(), 7);
+
+        let raised = ShamirSecretSharing::new(4, 7, None).unwrap();
+        assert_eq!(
+            raised.reconstruct_tuples(&new[3..]).unwrap(),
+            BigInt::from(1234),
+            "The secret should survive resharing"
+        );
+        assert_eq!(
+            raised.reconstruct_tuples(&old[..3]),
+            Err(SecretSharingError::InsufficientShares { got: 3, need: 4 })
+        );
+        assert_ne!(
+            shamir.reconstruct_with_threshold(&new[..3], 3).unwrap(),
+            BigInt::from(1234),
+            "Three new shares should no longer be enough"
+        );
+    }
+
+    #[test]
+    fn interpolate_at_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let expected = shamir.polynomial.evaluate(7) % &shamir.prime;
+        assert_eq!(
+            shamir
+                .interpolate_at(&shares[1..4], &BigInt::from(7))
+                .unwrap(),
+            expected,
+            "Interpolation at 7 should match evaluating the polynomial"
+        );
+        assert_eq!(
+            shamir.interpolate_at(&shares, &BigInt::from(0)).unwrap(),
+            BigInt::from(1234)
+        );
+        assert_eq!(
+            shamir.interpolate_at(&shares[..2], &BigInt::from(7)),
+            Err(SecretSharingError::InsufficientShares { got: 2, need: 3 })
+        );
+    }
+
+    #[test]
+    fn reconstruct_iter_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        let by_index: std::collections::HashMap<usize, BigInt> = shares.iter().cloned().collect();
+        assert_eq!(
+            shamir.reconstruct_iter(by_index).unwrap(),
+            BigInt::from(1234),
+            "A map of shares should reconstruct directly"
+        );
+        let [a, _, c, _, e] = <[(usize, BigInt); 5]>::try_from(shares.clone()).unwrap();
+        assert_eq!(
+            shamir.reconstruct_iter([a, c, e]).unwrap(),
+            BigInt::from(1234)
+        );
+        assert_eq!(
+            shamir.reconstruct_iter(shares.into_iter().filter(|(i, _)| i % 2 == 0)),
+            Err(SecretSharingError::InsufficientShares { got: 2, need: 3 })
+        );
+    }
+
+    #[test]
+    fn degenerate_prime_test() {
+        for prime in [1, 2] {
+            assert_eq!(
+                ShamirSecretSharing::new(1, 1, Some(BigInt::from(prime))).unwrap_err(),
+                SecretSharingError::PrimeTooSmall {
+                    prime: BigInt::from(prime)
+                },
+                "Prime {} should be rejected before any coefficient is drawn",
+                prime
+            );
+        }
+        assert!(ShamirSecretSharing::new(1, 2, Some(BigInt::from(3))).is_ok());
+    }
+
+    #[test]
+    fn clone_config_test() {
+        let mut original = ShamirSecretSharing::new(3, 5, None).unwrap();
+        original.generate_shares(BigInt::from(1111)).unwrap();
+
+        let mut copy = original.clone();
+        assert!(copy.config_eq(&original));
+        assert!(
+            copy.polynomial.coefficients.is_empty(),
+            "Cloning should not copy the secret"
+        );
+
+        let shares = copy.generate_shares(BigInt::from(2222)).unwrap();
+        assert_eq!(copy.reconstruct(&shares[..3]).unwrap(), BigInt::from(2222));
+        assert!(
+            original.verify_constant_term(&BigInt::from(1111)),
+            "The original dealing should be untouched"
+        );
+        assert!(!original.config_eq(&ShamirSecretSharing::new(3, 6, None).unwrap()));
+    }
+
+    #[test]
+    fn reconstruct_checked_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares_tuples(BigInt::from(1234)).unwrap();
+        assert_eq!(
+            shamir.reconstruct_checked(&shares[..4]).unwrap(),
+            BigInt::from(1234)
+        );
+        assert_eq!(
+            shamir.reconstruct_checked(&shares[..3]),
+            Err(SecretSharingError::InsufficientShares { got: 3, need: 4 }),
+            "Exactly threshold shares cannot be checked"
+        );
+        for bad in 0..4 {
+            let mut corrupted = shares[..4].to_vec();
+            corrupted[bad].1 += 1;
+            assert_eq!(
+                shamir.reconstruct_checked(&corrupted),
+                Err(SecretSharingError::InconsistentShares),
+                "Corrupting share {} should be detected",
+                bad
+            );
+        }
+    }
+
+    // counts events per level and keeps every recorded field so the test can check that no
+    // secret leaks into them
+    #[cfg(feature = "tracing")]
+    mod event_counter {
+        use std::fmt::Debug;
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Level, Metadata, Subscriber};
+
+        #[derive(Default)]
+        pub struct Recorded {
+            pub debug: usize,
+            pub warn: usize,
+            pub fields: Vec<String>,
+        }
+
+        #[derive(Default)]
+        pub struct EventCounter {
+            pub recorded: Arc<Mutex<Recorded>>,
+            next_span: AtomicU64,
+        }
+
+        struct FieldWriter<'a>(&'a mut Vec<String>);
+
+        impl Visit for FieldWriter<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for EventCounter {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                span.record(&mut FieldWriter(&mut self.recorded.lock().unwrap().fields));
+                Id::from_u64(self.next_span.fetch_add(1, Ordering::Relaxed) + 1)
+            }
+
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut FieldWriter(&mut self.recorded.lock().unwrap().fields));
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut recorded = self.recorded.lock().unwrap();
+                match *event.metadata().level() {
+                    Level::DEBUG => recorded.debug += 1,
+                    Level::WARN => recorded.warn += 1,
+                    _ => {}
+                }
+                event.record(&mut FieldWriter(&mut recorded.fields));
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_events_test() {
+        let counter = event_counter::EventCounter::default();
+        let recorded = counter.recorded.clone();
+        let secret = BigInt::from(987654321);
+        tracing::subscriber::with_default(counter, || {
+            let mut feldman = FeldmanVSS::new(3, 5, None).unwrap();
+            let response = feldman.generate_shares(secret.clone()).unwrap();
+            let shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+            assert_eq!(shamir.reconstruct(&response.shares[..3]).unwrap(), secret);
+            assert!(shamir.reconstruct(&response.shares[..2]).is_err());
+        });
+
+        let recorded = recorded.lock().unwrap();
+        // share generation, commitments and the successful interpolation
+        assert_eq!(recorded.debug, 3, "Three debug events expected");
+        assert_eq!(recorded.warn, 1, "The rejected reconstruction should warn");
+        assert!(
+            recorded
+                .fields
+                .iter()
+                .all(|field| !field.contains("987654321")),
+            "The secret should never be logged"
+        );
+    }
+
+    #[test]
+    fn random_subsets_reconstruct_test() {
+        use num_bigint::RandBigInt;
+        use rand::seq::index::sample;
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let threshold = rng.gen_range(1..=6);
+            let total_shares = rng.gen_range(threshold..=10);
+            let mut shamir = ShamirSecretSharing::new(threshold, total_shares, None).unwrap();
+            let secret = rng.gen_bigint_range(&BigInt::from(0), &default_prime());
+            let shares = shamir.generate_shares_tuples(secret.clone()).unwrap();
+
+            // any threshold sized subset, in random order
+            let subset: Vec<(usize, BigInt)> = sample(&mut rng, total_shares, threshold)
+                .into_iter()
+                .map(|i| shares[i].clone())
+                .collect();
+            assert_eq!(
+                shamir.reconstruct_tuples(&subset).unwrap(),
+                secret,
+                "A {}-of-{} subset should reconstruct the secret",
+                threshold,
+                total_shares
+            );
+            let (xs, ys): (Vec<usize>, Vec<BigInt>) = subset.into_iter().unzip();
+            assert_eq!(shamir.lagrange_interpolation(xs, ys).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn indices_above_prime_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let indices = [usize::MAX, usize::MAX - 1, usize::MAX - 2];
+        let shares = shamir
+            .generate_shares_with_indices(BigInt::from(4242), &indices)
+            .unwrap();
+        assert_eq!(
+            shamir.reconstruct_tuples(&shares).unwrap(),
+            BigInt::from(4242),
+            "Indices far above the prime should be reduced before interpolation"
+        );
+        let (xs, ys): (Vec<usize>, Vec<BigInt>) = shares.iter().cloned().unzip();
+        assert_eq!(
+            shamir.lagrange_interpolation(xs, ys).unwrap(),
+            BigInt::from(4242)
+        );
+
+        // 2^64 - 1 is 3 mod 2^31 - 1
+        let mut congruent = shares[..2].to_vec();
+        congruent.push((3, shares[2].1.clone()));
+        assert_eq!(
+            shamir.reconstruct_tuples(&congruent),
+            Err(SecretSharingError::CongruentShareIndices {
+                first: BigInt::from(usize::MAX),
+                second: BigInt::from(3)
+            })
+        );
+    }
+
+    #[test]
+    fn congruent_indices_every_entry_point_test() {
+        use crate::algorithms::packed::combine_packed;
+
+        // 2^31 is 1 mod 2^31 - 1, so both shares sit at the same field element
+        let shamir = ShamirSecretSharing::new(2, 3, None).unwrap();
+        let shares = vec![(1, BigInt::from(5)), (2147483648, BigInt::from(5))];
+        let congruent = SecretSharingError::CongruentShareIndices {
+            first: BigInt::from(1),
+            second: BigInt::from(2147483648u64),
+        };
+        assert_eq!(shamir.reconstruct_tuples(&shares), Err(congruent.clone()));
+        assert_eq!(
+            shamir.reconstruct_with_threshold(&shares, 2),
+            Err(congruent.clone())
+        );
+        let (xs, ys): (Vec<usize>, Vec<BigInt>) = shares.iter().cloned().unzip();
+        assert_eq!(
+            shamir.lagrange_interpolation(xs, ys),
+            Err(congruent.clone())
+        );
+        assert_eq!(combine_packed(&shares, 1, 1), Err(congruent.clone()));
+
+        let mut padded = shares.clone();
+        padded.extend([(2, BigInt::from(9)), (3, BigInt::from(13))]);
+        assert_eq!(
+            shamir.reconstruct_with_errors(&padded, 1),
+            Err(congruent),
+            "Berlekamp-Welch should reject congruent indices as well"
+        );
+    }
+
+    #[test]
+    fn shares_needed_test() {
+        let shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        assert_eq!(shamir.shares_needed(0), 3);
+        assert_eq!(shamir.shares_needed(2), 1);
+        assert!(!shamir.can_reconstruct(2), "Two of three is not enough");
+        assert_eq!(shamir.shares_needed(3), 0);
+        assert!(shamir.can_reconstruct(3));
+        assert_eq!(
+            shamir.shares_needed(5),
+            0,
+            "Extra shares should not underflow"
+        );
+        assert!(shamir.can_reconstruct(5));
+    }
+
+    #[test]
+    fn default_prime_test() {
+        assert_eq!(default_prime(), BigInt::from(2147483647u32));
+        assert_eq!(
+            ShamirSecretSharing::new(2, 3, None).unwrap().prime,
+            default_prime(),
+            "new should fall back to default_prime"
+        );
+        assert_eq!(
+            *FeldmanVSS::new(2, 3, None).unwrap().prime(),
+            default_prime()
+        );
+    }
 }
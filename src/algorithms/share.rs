@@ -0,0 +1,273 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+use core::str::FromStr;
+
+use num_bigint::{BigInt, Sign};
+use sha2::{Digest, Sha256};
+
+use super::encoding::{bigint_to_hex, share_from_hex, share_to_fixed_bytes};
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+// one participant's share as returned by generate_shares, prints as "index:hexvalue:checksum"
+// and parses with or without the checksum. converts to and from the (index, value) tuple
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share {
+    pub index: usize,
+    pub value: BigInt,
+}
+
+impl From<(usize, BigInt)> for Share {
+    fn from((index, value): (usize, BigInt)) -> Self {
+        Share { index, value }
+    }
+}
+
+impl From<Share> for (usize, BigInt) {
+    fn from(share: Share) -> Self {
+        (share.index, share.value)
+    }
+}
+
+// inverse of Share::to_bytes: an 8 byte big-endian index followed by the big-endian value.
+// every slice decodes, a slice shorter than 8 bytes is all index with a zero value
+impl From<&[u8]> for Share {
+    fn from(bytes: &[u8]) -> Self {
+        let (index, value) = bytes.split_at(bytes.len().min(8));
+        Share {
+            index: index.iter().fold(0, |acc, b| (acc << 8) | *b as usize),
+            value: BigInt::from_bytes_be(Sign::Plus, value),
+        }
+    }
+}
+
+impl fmt::Display for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.index,
+            bigint_to_hex(&self.value),
+            self.checksum()
+        )
+    }
+}
+
+// surrounding whitespace and whitespace around the colons are ignored. a checksum, when
+// present, has to match so transcription errors are caught before reconstruction
+impl FromStr for Share {
+    type Err = SecretSharingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (share, checksum) = match s.matches(':').count() {
+            2 => {
+                let (share, checksum) = s.rsplit_once(':').expect("two colons");
+                (share, Some(checksum.trim()))
+            }
+            _ => (s, None),
+        };
+        let share = Share::from(share_from_hex(share)?);
+        match checksum {
+            Some(checksum) if !checksum.eq_ignore_ascii_case(&share.checksum()) => Err(
+                SecretSharingError::InvalidEncoding("share checksum mismatch"),
+            ),
+            _ => Ok(share),
+        }
+    }
+}
+
+impl Share {
+    // first 4 bytes of sha256 over the index and the value bytes, as 8 hex digits
+    fn checksum(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update((self.index as u64).to_be_bytes());
+        hasher.update(self.value.to_signed_bytes_be());
+        let digest = hasher.finalize();
+        digest[..4].iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    // compact binary form, the index as 8 big-endian bytes then the value's magnitude
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (_, value) = self.value.to_bytes_be();
+        let mut bytes = (self.index as u64).to_be_bytes().to_vec();
+        bytes.extend_from_slice(&value);
+        bytes
+    }
+
+    // "index:hexvalue" with the value zero padded to exactly width bytes, so every share of one
+    // dealing prints at the same length. ShamirSecretSharing::share_byte_len gives the width
+    pub fn to_fixed_hex(&self, width: usize) -> Result<String, SecretSharingError> {
+        let (index, bytes) = share_to_fixed_bytes(&(self.index, self.value.clone()), width)?;
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(format!("{}:{}", index, hex))
+    }
+
+    // inverse of to_fixed_hex, the value has to be exactly width bytes of hex
+    pub fn from_fixed_hex(s: &str, width: usize) -> Result<Self, SecretSharingError> {
+        let hex = s
+            .split_once(':')
+            .map(|(_, value)| value.trim())
+            .unwrap_or("");
+        if hex.len() != 2 * width {
+            return Err(SecretSharingError::InvalidEncoding(
+                "share value is not fixed width",
+            ));
+        }
+        Ok(Share::from(share_from_hex(s)?))
+    }
+}
+
+impl ShamirSecretSharing {
+    // bytes needed for any share value this instance can deal. shares are reduced mod prime,
+    // so this is the byte length of the prime
+    pub fn share_byte_len(&self) -> usize {
+        self.prime.bits().div_ceil(8) as usize
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::algorithms::share::Share;
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn share_display_from_str_test() {
+        let share = Share {
+            index: 3,
+            value: BigInt::from(0xabc123),
+        };
+        let printed = share.to_string();
+        assert!(printed.starts_with("3:abc123:"));
+        assert_eq!(printed.len(), "3:abc123:".len() + 8, "8 hex digit checksum");
+        assert_eq!(printed.parse::<Share>().unwrap(), share);
+        assert_eq!(
+            "3:abc123".parse::<Share>().unwrap(),
+            share,
+            "The checksum is optional"
+        );
+        let checksum = printed.rsplit(':').next().unwrap();
+        assert_eq!(
+            format!("  3 :\tabc123 : {}\n", checksum.to_uppercase())
+                .parse::<Share>()
+                .unwrap(),
+            share,
+            "Whitespace around the parts should be tolerated"
+        );
+        assert_eq!(
+            "3abc123".parse::<Share>(),
+            Err(SecretSharingError::InvalidEncoding(
+                "share is not index:hexvalue"
+            ))
+        );
+    }
+
+    #[test]
+    fn shares_round_trip_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares(BigInt::from(1234)).unwrap();
+        let parsed: Vec<Share> = shares
+            .iter()
+            .map(|share| share.to_string().parse().unwrap())
+            .collect();
+        assert_eq!(parsed, shares, "Shares should survive printing and parsing");
+        assert_eq!(
+            shamir.reconstruct(&parsed[1..4]).unwrap(),
+            BigInt::from(1234)
+        );
+    }
+
+    #[test]
+    fn share_checksum_test() {
+        let share = Share {
+            index: 2,
+            value: BigInt::from(0x1234_5678),
+        };
+        let printed = share.to_string();
+        // a typo in the value: flip one hex digit
+        let typo = printed.replacen("12345678", "12345679", 1);
+        assert_ne!(typo, printed);
+        assert_eq!(
+            typo.parse::<Share>(),
+            Err(SecretSharingError::InvalidEncoding(
+                "share checksum mismatch"
+            )),
+            "A flipped digit should fail the checksum"
+        );
+        let wrong_index = printed.replacen("2:", "3:", 1);
+        assert!(wrong_index.parse::<Share>().is_err());
+    }
+
+    #[test]
+    fn fixed_hex_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        let shares = shamir.generate_shares(BigInt::from(7)).unwrap();
+        let width = shamir.share_byte_len();
+        assert_eq!(width, 4, "Shares are reduced mod 2^31 - 1");
+        let printed: Vec<String> = shares
+            .iter()
+            .map(|share| share.to_fixed_hex(width).unwrap())
+            .collect();
+        for line in printed.iter() {
+            assert_eq!(
+                line.len(),
+                printed[0].len(),
+                "Every share of one dealing should print at the same length"
+            );
+        }
+        let parsed: Vec<Share> = printed
+            .iter()
+            .map(|line| Share::from_fixed_hex(line, width).unwrap())
+            .collect();
+        assert_eq!(
+            parsed, shares,
+            "Fixed width shares should parse back exactly"
+        );
+
+        let small = Share {
+            index: 1,
+            value: BigInt::from(0xab),
+        };
+        assert_eq!(small.to_fixed_hex(3).unwrap(), "1:0000ab");
+        assert_eq!(
+            Share::from_fixed_hex("1:ab", 3),
+            Err(SecretSharingError::InvalidEncoding(
+                "share value is not fixed width"
+            ))
+        );
+        assert!(
+            small.to_fixed_hex(0).is_err(),
+            "The value has to fit the width"
+        );
+    }
+
+    #[test]
+    fn bytes_round_trip_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 5, None).unwrap();
+        for share in shamir.generate_shares(BigInt::from(1234)).unwrap() {
+            assert_eq!(
+                Share::from(share.to_bytes().as_slice()),
+                share,
+                "Shares should survive to_bytes and From<&[u8]>"
+            );
+        }
+
+        let bytes = [0, 0, 0, 0, 0, 0, 1, 2, 0xab, 0xcd];
+        assert_eq!(
+            Share::from(&bytes[..]),
+            Share {
+                index: 0x102,
+                value: BigInt::from(0xabcd),
+            }
+        );
+        assert_eq!(
+            Share::from(&bytes[6..8]),
+            Share {
+                index: 0x102,
+                value: BigInt::from(0),
+            },
+            "A short slice is all index"
+        );
+    }
+}
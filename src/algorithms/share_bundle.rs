@@ -0,0 +1,173 @@
+use alloc::{string::String, vec::Vec};
+
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+// shares packaged with the parameters needed to combine them, so a combiner does not have to
+// know (or guess) threshold and prime out of band
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShareBundle {
+    pub threshold: usize,
+    pub prime: BigInt,
+    pub shares: Vec<(usize, BigInt)>,
+}
+
+impl ShareBundle {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SecretSharingError> {
+        serde_json::to_vec(self)
+            .map_err(|_| SecretSharingError::InvalidEncoding("unserializable share bundle"))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SecretSharingError> {
+        serde_json::from_slice(bytes)
+            .map_err(|_| SecretSharingError::InvalidEncoding("malformed share bundle"))
+    }
+}
+
+impl ShamirSecretSharing {
+    // bundle shares of this instance's dealing with its threshold and prime
+    pub fn bundle(&self, shares: &[(usize, BigInt)]) -> ShareBundle {
+        ShareBundle {
+            threshold: self.threshold,
+            prime: self.prime.clone(),
+            shares: shares.to_vec(),
+        }
+    }
+
+    // reconstruct using only what the bundle carries. the parameters go through the same
+    // validation as new, so a bundle with a composite prime or zero threshold is rejected
+    pub fn reconstruct_bundle(bundle: &ShareBundle) -> Result<BigInt, SecretSharingError> {
+        let total_shares = bundle.shares.len().max(bundle.threshold);
+        let shamir = Self::new(bundle.threshold, total_shares, Some(bundle.prime.clone()))?;
+        shamir.reconstruct_tuples(&bundle.shares)
+    }
+}
+
+// one self-describing string per participant: a single share bundle as json. the prime is the
+// default one unless the secret needs a larger prime
+#[cfg(feature = "std")]
+pub fn split(
+    threshold: usize,
+    total_shares: usize,
+    secret: BigInt,
+) -> Result<Vec<String>, SecretSharingError> {
+    let mut shamir = ShamirSecretSharing::for_secret(threshold, total_shares, &secret)?;
+    let shares = shamir.generate_shares_tuples(secret)?;
+    shares
+        .iter()
+        .map(|share| {
+            let bytes = shamir.bundle(core::slice::from_ref(share)).to_bytes()?;
+            String::from_utf8(bytes)
+                .map_err(|_| SecretSharingError::InvalidEncoding("share bundle is not utf-8"))
+        })
+        .collect()
+}
+
+// reconstruct from share bundle strings alone, e.g. the output of split. every bundle has to
+// carry the same threshold and prime
+pub fn combine(shares: &[String]) -> Result<BigInt, SecretSharingError> {
+    let bundles = shares
+        .iter()
+        .map(|share| ShareBundle::from_bytes(share.as_bytes()))
+        .collect::<Result<Vec<ShareBundle>, _>>()?;
+    let Some(first) = bundles.first() else {
+        return Err(SecretSharingError::InsufficientShares { got: 0, need: 1 });
+    };
+    if bundles.iter().any(|b| b.threshold != first.threshold) {
+        return Err(SecretSharingError::IncompatibleDealing(
+            "share bundles disagree on the threshold",
+        ));
+    }
+    if bundles.iter().any(|b| b.prime != first.prime) {
+        return Err(SecretSharingError::IncompatibleDealing(
+            "share bundles disagree on the prime",
+        ));
+    }
+    let merged = ShareBundle {
+        threshold: first.threshold,
+        prime: first.prime.clone(),
+        shares: bundles.iter().flat_map(|b| b.shares.clone()).collect(),
+    };
+    ShamirSecretSharing::reconstruct_bundle(&merged)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::algorithms::share_bundle::{combine, split, ShareBundle};
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn bundle_round_trip_test() {
+        let bytes = {
+            let mut dealer = ShamirSecretSharing::new(3, 5, Some(BigInt::from(7919))).unwrap();
+            let shares = dealer.generate_shares_tuples(BigInt::from(1234)).unwrap();
+            dealer.bundle(&shares[1..4]).to_bytes().unwrap()
+        };
+
+        // the combiner only ever sees the bytes
+        let bundle = ShareBundle::from_bytes(&bytes).unwrap();
+        assert_eq!(bundle.threshold, 3);
+        assert_eq!(bundle.prime, BigInt::from(7919));
+        assert_eq!(
+            ShamirSecretSharing::reconstruct_bundle(&bundle).unwrap(),
+            BigInt::from(1234),
+            "Bundle should reconstruct without outside parameters"
+        );
+    }
+
+    #[test]
+    fn bundle_validation_test() {
+        assert!(ShareBundle::from_bytes(b"not a bundle").is_err());
+
+        let bundle = ShareBundle {
+            threshold: 2,
+            prime: BigInt::from(15),
+            shares: vec![(1, BigInt::from(3)), (2, BigInt::from(5))],
+        };
+        assert_eq!(
+            ShamirSecretSharing::reconstruct_bundle(&bundle),
+            Err(SecretSharingError::NonPrimeModulus {
+                modulus: BigInt::from(15)
+            }),
+            "Bundle parameters should be validated"
+        );
+    }
+
+    #[test]
+    fn split_combine_strings_test() {
+        let shares = split(3, 5, BigInt::from(2024)).unwrap();
+        assert_eq!(shares.len(), 5);
+        // no ShamirSecretSharing in sight, the strings carry threshold and prime
+        assert_eq!(combine(&shares[2..]).unwrap(), BigInt::from(2024));
+        assert_eq!(
+            crate::combine(&[shares[4].clone(), shares[0].clone(), shares[1].clone()]).unwrap(),
+            BigInt::from(2024),
+            "The crate root should re-export combine"
+        );
+        assert_eq!(
+            combine(&shares[..2]),
+            Err(SecretSharingError::InsufficientShares { got: 2, need: 3 })
+        );
+
+        let other = split(2, 5, BigInt::from(7)).unwrap();
+        assert_eq!(
+            combine(&[shares[0].clone(), shares[1].clone(), other[2].clone()]),
+            Err(SecretSharingError::IncompatibleDealing(
+                "share bundles disagree on the threshold"
+            ))
+        );
+        let large = split(3, 5, BigInt::from(1u64 << 40)).unwrap();
+        assert_eq!(
+            combine(&[shares[0].clone(), shares[1].clone(), large[2].clone()]),
+            Err(SecretSharingError::IncompatibleDealing(
+                "share bundles disagree on the prime"
+            ))
+        );
+        assert!(combine(&["not json".to_string()]).is_err());
+    }
+}
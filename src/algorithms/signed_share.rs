@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
+
+use super::feldman_vss::{verify_share, FeldmanVSS};
+
+// checks a dealer's signature over a message, e.g. ed25519 or ecdsa behind a thin adapter
+pub trait SignatureVerifier {
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+}
+
+// canonical bytes the dealer signs for a share: index then length prefixed value
+pub fn signed_share_message(share: &(usize, BigInt)) -> Vec<u8> {
+    let value = share.1.to_signed_bytes_be();
+    let mut message = Vec::with_capacity(16 + value.len());
+    message.extend_from_slice(&(share.0 as u64).to_be_bytes());
+    message.extend_from_slice(&(value.len() as u64).to_be_bytes());
+    message.extend_from_slice(&value);
+    message
+}
+
+impl FeldmanVSS {
+    // a share is accepted only if the dealer signed it and it satisfies the commitment equation,
+    // which binds the share to that dealer and not just to some consistent polynomial
+    pub fn verify_signed_share(
+        &self,
+        share: &(usize, BigInt),
+        signature: &[u8],
+        dealer_pubkey: &[u8],
+        committments: &[BigInt],
+        verifier: &dyn SignatureVerifier,
+    ) -> bool {
+        verifier.verify(dealer_pubkey, &signed_share_message(share), signature)
+            && verify_share(self.group(), committments, share.clone())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::feldman_vss::FeldmanVSS;
+    use crate::algorithms::shamir_secret_sharing::default_prime;
+    use crate::algorithms::signed_share::{signed_share_message, SignatureVerifier};
+    use num_bigint::BigInt;
+    use sha2::{Digest, Sha256};
+
+    // sha256(key || message) with the "public" key doubling as the secret, only good enough for tests
+    struct HashSigner;
+
+    impl HashSigner {
+        fn sign(key: &[u8], message: &[u8]) -> Vec<u8> {
+            Sha256::new()
+                .chain_update(key)
+                .chain_update(message)
+                .finalize()
+                .to_vec()
+        }
+    }
+
+    impl SignatureVerifier for HashSigner {
+        fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+            Self::sign(public_key, message) == signature
+        }
+    }
+
+    #[test]
+    fn verify_signed_share_test() {
+        let dealer_key = b"dealer key";
+        let mut vss = FeldmanVSS::new(3, 5, Some(default_prime())).unwrap();
+        let response = vss.generate_shares(BigInt::from(1234)).unwrap();
+        let share = response.shares[1].clone();
+        let signature = HashSigner::sign(dealer_key, &signed_share_message(&share));
+
+        assert!(
+            vss.verify_signed_share(
+                &share,
+                &signature,
+                dealer_key,
+                &response.committments,
+                &HashSigner
+            ),
+            "Signed valid share should be accepted"
+        );
+        assert!(
+            !vss.verify_signed_share(&share, &[], dealer_key, &response.committments, &HashSigner),
+            "Valid but unsigned share should be rejected"
+        );
+
+        let forged = (share.0, &share.1 + 1);
+        let forged_signature = HashSigner::sign(dealer_key, &signed_share_message(&forged));
+        assert!(
+            !vss.verify_signed_share(
+                &forged,
+                &forged_signature,
+                dealer_key,
+                &response.committments,
+                &HashSigner
+            ),
+            "Signed share failing the commitments should be rejected"
+        );
+    }
+}
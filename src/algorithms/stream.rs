@@ -0,0 +1,206 @@
+use alloc::{vec, vec::Vec};
+
+use std::io::{self, Read, Write};
+
+use num_bigint::{BigInt, Sign};
+
+use super::encoding::share_to_fixed_bytes;
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+impl From<io::Error> for SecretSharingError {
+    fn from(err: io::Error) -> Self {
+        SecretSharingError::Io { kind: err.kind() }
+    }
+}
+
+impl ShamirSecretSharing {
+    // split_bytes without holding the input in memory: every chunk_size block of input is
+    // shared on its own and outputs[k] receives share k + 1 of each block as a fixed width
+    // value, the prime's byte length. the input ends with the same 0x80 00.. padding
+    pub fn split_stream<R: Read, W: Write>(
+        &mut self,
+        mut input: R,
+        outputs: &mut [W],
+    ) -> Result<(), SecretSharingError> {
+        let chunk_size = self.chunk_size();
+        if chunk_size == 0 {
+            return Err(SecretSharingError::PrimeTooSmall {
+                prime: self.prime.clone(),
+            });
+        }
+        if outputs.len() != self.total_shares {
+            return Err(SecretSharingError::IncompatibleDealing(
+                "one output per share is needed",
+            ));
+        }
+        let width = self.value_width();
+        let mut block = vec![0; chunk_size];
+        loop {
+            let read = read_full(&mut input, &mut block)?;
+            let last = read < chunk_size;
+            if last {
+                block[read] = 0x80;
+                block[read + 1..].fill(0);
+            }
+            let shares = self.generate_shares_tuples(BigInt::from_bytes_be(Sign::Plus, &block))?;
+            for ((i, y), output) in shares.into_iter().zip(outputs.iter_mut()) {
+                let (_, bytes) = share_to_fixed_bytes(&(i, y % &self.prime), width)?;
+                output.write_all(&bytes)?;
+            }
+            if last {
+                return Ok(());
+            }
+        }
+    }
+
+    // reassemble a split_stream output from (index, share stream) pairs. one decoded block is
+    // held back so the padding can be stripped once every stream ends
+    pub fn combine_stream<R: Read, W: Write>(
+        &self,
+        inputs: &mut [(usize, R)],
+        mut output: W,
+    ) -> Result<(), SecretSharingError> {
+        let chunk_size = self.chunk_size();
+        let width = self.value_width();
+        let mut value = vec![0; width];
+        let mut pending: Option<Vec<u8>> = None;
+        loop {
+            let mut block_shares = Vec::with_capacity(inputs.len());
+            for (index, input) in inputs.iter_mut() {
+                match read_full(input, &mut value)? {
+                    0 => break,
+                    n if n == width => {
+                        block_shares.push((*index, BigInt::from_bytes_be(Sign::Plus, &value)))
+                    }
+                    _ => {
+                        return Err(SecretSharingError::InvalidEncoding(
+                            "truncated share stream",
+                        ))
+                    }
+                }
+            }
+            if block_shares.is_empty() {
+                break;
+            }
+            if block_shares.len() != inputs.len() {
+                return Err(SecretSharingError::InvalidEncoding(
+                    "share streams differ in length",
+                ));
+            }
+
+            let (_, bytes) = self.reconstruct_tuples(&block_shares)?.to_bytes_be();
+            if bytes.len() > chunk_size {
+                return Err(SecretSharingError::InvalidEncoding(
+                    "block exceeds chunk size",
+                ));
+            }
+            // restore leading zeros dropped by the integer conversion
+            let mut block = vec![0; chunk_size - bytes.len()];
+            block.extend_from_slice(&bytes);
+            if let Some(previous) = pending.replace(block) {
+                output.write_all(&previous)?;
+            }
+        }
+
+        // strip the 0x80 00.. padding from the final block
+        let mut last = pending.ok_or(SecretSharingError::InvalidEncoding("empty share stream"))?;
+        match last.iter().rposition(|b| *b != 0) {
+            Some(end) if last[end] == 0x80 => {
+                last.truncate(end);
+                output.write_all(&last)?;
+                Ok(())
+            }
+            _ => Err(SecretSharingError::InvalidEncoding(
+                "missing padding marker",
+            )),
+        }
+    }
+
+    // bytes per share value in a stream, enough for any value mod prime
+    fn value_width(&self) -> usize {
+        self.prime.bits().div_ceil(8) as usize
+    }
+}
+
+// fill buf from reader, short only at end of input
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    // 2^521 - 1, 65 byte blocks keep the 1 MB test quick
+    fn shamir_521() -> ShamirSecretSharing {
+        let prime = (BigInt::from(1) << 521) - 1;
+        ShamirSecretSharing::new(3, 5, Some(prime)).unwrap()
+    }
+
+    #[test]
+    fn stream_round_trip_test() {
+        let mut shamir = shamir_521();
+        let data: Vec<u8> = (0..1 << 20).map(|i: u32| (i * 31 % 251) as u8).collect();
+        let mut outputs = vec![Vec::new(); 5];
+        shamir.split_stream(data.as_slice(), &mut outputs).unwrap();
+
+        let mut inputs: Vec<(usize, &[u8])> = vec![
+            (2, outputs[1].as_slice()),
+            (4, outputs[3].as_slice()),
+            (5, outputs[4].as_slice()),
+        ];
+        let mut combined = Vec::new();
+        shamir.combine_stream(&mut inputs, &mut combined).unwrap();
+        assert!(
+            combined == data,
+            "1 MB should round trip through the streams"
+        );
+    }
+
+    #[test]
+    fn stream_edge_cases_test() {
+        let mut shamir = shamir_521();
+        for data in [&b""[..], &[0u8; 65][..], &[0xff; 66][..]] {
+            let mut outputs = vec![Vec::new(); 5];
+            shamir.split_stream(data, &mut outputs).unwrap();
+            let mut inputs: Vec<(usize, &[u8])> =
+                (0..3).map(|k| (k + 1, outputs[k].as_slice())).collect();
+            let mut combined = Vec::new();
+            shamir.combine_stream(&mut inputs, &mut combined).unwrap();
+            assert_eq!(combined, data, "{} bytes should round trip", data.len());
+        }
+
+        let mut outputs = vec![Vec::new(); 5];
+        shamir.split_stream(&b"secret"[..], &mut outputs).unwrap();
+        let truncated = &outputs[2][..outputs[2].len() - 1];
+        let mut inputs: Vec<(usize, &[u8])> = vec![
+            (1, outputs[0].as_slice()),
+            (2, outputs[1].as_slice()),
+            (3, truncated),
+        ];
+        assert_eq!(
+            shamir.combine_stream(&mut inputs, Vec::new()),
+            Err(SecretSharingError::InvalidEncoding(
+                "truncated share stream"
+            ))
+        );
+        assert_eq!(
+            shamir.split_stream(&b"secret"[..], &mut outputs[..4]),
+            Err(SecretSharingError::IncompatibleDealing(
+                "one output per share is needed"
+            ))
+        );
+    }
+}
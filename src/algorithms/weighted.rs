@@ -0,0 +1,99 @@
+use alloc::{string::String, vec::Vec};
+
+use std::collections::HashMap;
+
+use num_bigint::BigInt;
+
+use super::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+impl ShamirSecretSharing {
+    // weighted sharing: a participant of weight w gets w shares at distinct x coordinates, so
+    // any group whose weights add up to the threshold can reconstruct. the weights together
+    // may not exceed total_shares
+    pub fn generate_weighted_shares(
+        &mut self,
+        secret: BigInt,
+        weights: &[(String, usize)],
+    ) -> Result<HashMap<String, Vec<(usize, BigInt)>>, SecretSharingError> {
+        for (n, (name, _)) in weights.iter().enumerate() {
+            if weights[..n].iter().any(|(other, _)| other == name) {
+                return Err(SecretSharingError::DuplicateParticipant { name: name.clone() });
+            }
+        }
+        let total_weight: usize = weights.iter().map(|(_, weight)| weight).sum();
+        if total_weight > self.total_shares {
+            return Err(SecretSharingError::TotalSharesAboveLimit {
+                total_shares: total_weight,
+                max: self.total_shares,
+            });
+        }
+
+        let indices: Vec<usize> = (1..=total_weight).collect();
+        let mut shares = self
+            .generate_shares_with_indices(secret, &indices)?
+            .into_iter();
+        Ok(weights
+            .iter()
+            .map(|(name, weight)| (name.clone(), shares.by_ref().take(*weight).collect()))
+            .collect())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+    use crate::error::SecretSharingError;
+    use num_bigint::BigInt;
+
+    fn weights(list: &[(&str, usize)]) -> Vec<(String, usize)> {
+        list.iter().map(|(n, w)| (n.to_string(), *w)).collect()
+    }
+
+    #[test]
+    fn weighted_shares_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 6, None).unwrap();
+        let dealt = shamir
+            .generate_weighted_shares(
+                BigInt::from(1234),
+                &weights(&[("ceo", 2), ("alice", 1), ("bob", 1), ("carol", 1)]),
+            )
+            .unwrap();
+        assert_eq!(dealt["ceo"].len(), 2, "The ceo should hold two shares");
+        assert_eq!(dealt["bob"].len(), 1);
+
+        let mut pooled = dealt["ceo"].clone();
+        pooled.extend(dealt["carol"].iter().cloned());
+        assert_eq!(
+            shamir.reconstruct_tuples(&pooled).unwrap(),
+            BigInt::from(1234),
+            "The ceo and one manager together meet the threshold"
+        );
+
+        let mut managers = dealt["alice"].clone();
+        managers.extend(dealt["bob"].iter().cloned());
+        assert_eq!(
+            shamir.reconstruct_tuples(&managers),
+            Err(SecretSharingError::InsufficientShares { got: 2, need: 3 }),
+            "Two managers alone should fall short"
+        );
+    }
+
+    #[test]
+    fn weighted_shares_validation_test() {
+        let mut shamir = ShamirSecretSharing::new(3, 4, None).unwrap();
+        assert_eq!(
+            shamir.generate_weighted_shares(BigInt::from(1), &weights(&[("a", 3), ("b", 2)])),
+            Err(SecretSharingError::TotalSharesAboveLimit {
+                total_shares: 5,
+                max: 4
+            })
+        );
+        assert_eq!(
+            shamir.generate_weighted_shares(BigInt::from(1), &weights(&[("a", 1), ("a", 2)])),
+            Err(SecretSharingError::DuplicateParticipant {
+                name: "a".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use num_bigint::BigInt;
+use secret_sharing::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+use secret_sharing::error::SecretSharingError;
+
+#[derive(Parser)]
+#[command(
+    name = "sss",
+    about = "Split and combine secrets with Shamir's secret sharing"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Split a decimal secret and print one share per line as index:value
+    Split {
+        #[arg(long)]
+        threshold: usize,
+        #[arg(long)]
+        shares: usize,
+        #[arg(long)]
+        secret: BigInt,
+        /// Field prime, defaults to 2^31 - 1
+        #[arg(long)]
+        prime: Option<BigInt>,
+    },
+    /// Combine index:value shares and print the secret, every given share is interpolated
+    Combine {
+        #[arg(long = "share", value_parser = parse_share, required = true)]
+        shares: Vec<(usize, BigInt)>,
+        #[arg(long)]
+        prime: Option<BigInt>,
+    },
+}
+
+fn parse_share(s: &str) -> Result<(usize, BigInt), String> {
+    let (index, value) = s
+        .split_once(':')
+        .ok_or_else(|| format!("share {s:?} is not index:value"))?;
+    let index = index
+        .parse()
+        .map_err(|_| format!("invalid share index {index:?}"))?;
+    let value = value
+        .parse()
+        .map_err(|_| format!("invalid share value {value:?}"))?;
+    Ok((index, value))
+}
+
+fn run(cli: Cli) -> Result<(), SecretSharingError> {
+    match cli.command {
+        Command::Split {
+            threshold,
+            shares,
+            secret,
+            prime,
+        } => {
+            let mut shamir = ShamirSecretSharing::new(threshold, shares, prime)?;
+            for share in shamir.generate_shares(secret)? {
+                println!("{}:{}", share.index, share.value);
+            }
+        }
+        Command::Combine { shares, prime } => {
+            let shamir = ShamirSecretSharing::new(shares.len(), shares.len(), prime)?;
+            println!("{}", shamir.reconstruct_tuples(&shares)?);
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use alloc::string::String;
+
+use num_bigint::BigInt;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SecretSharingError {
+    #[error("threshold {threshold} has to be at most total shares {total_shares}")]
+    ThresholdExceedsShares {
+        threshold: usize,
+        total_shares: usize,
+    },
+    #[error("threshold has to be at least 1")]
+    ZeroThreshold,
+    #[error("secret has to be smaller than the prime {prime}")]
+    SecretTooLarge { prime: BigInt },
+    #[error("require at least {need} shares, got {got}")]
+    InsufficientShares { got: usize, need: usize },
+    #[error("modulus {modulus} is not a prime")]
+    NonPrimeModulus { modulus: BigInt },
+    #[error("share index {index} is invalid")]
+    InvalidShareIndex { index: usize },
+    #[error("share {index} failed verification against the commitments")]
+    ShareVerificationFailed { index: usize },
+    #[error("could not generate nonzero shares after {attempts} attempts")]
+    NonzeroSharesExhausted { attempts: usize },
+    #[error("prime {prime} is too small for this operation")]
+    PrimeTooSmall { prime: BigInt },
+    #[error("invalid encoding: {0}")]
+    InvalidEncoding(&'static str),
+    #[error("reconstructed secret has {got_bits} bits, expected at most {expected_bits} (possible wraparound mod prime)")]
+    PossibleWraparound { got_bits: u64, expected_bits: u64 },
+    #[error("share index {index} appears more than once")]
+    DuplicateShareIndex { index: usize },
+    #[error("incompatible dealing: {0}")]
+    IncompatibleDealing(&'static str),
+    #[error("reconstructed secret does not match the commitment C0")]
+    CommitmentMismatch,
+    #[error("shares could not be decoded, too many of them are corrupted")]
+    DecodingFailed,
+    #[error("share indices {first} and {second} are congruent mod the prime")]
+    CongruentShareIndices { first: BigInt, second: BigInt },
+    #[error("threshold {threshold} exceeds configured maximum {max}")]
+    ThresholdAboveLimit { threshold: usize, max: usize },
+    #[error("total shares {total_shares} exceeds configured maximum {max}")]
+    TotalSharesAboveLimit { total_shares: usize, max: usize },
+    #[error("total shares {total_shares} has to be smaller than the prime {prime}")]
+    SharesExceedPrime { total_shares: usize, prime: BigInt },
+    #[error("{generator} does not generate the prime order commitment subgroup")]
+    InvalidGenerator { generator: BigInt },
+    #[error("participant {name} appears more than once")]
+    DuplicateParticipant { name: String },
+    #[cfg(feature = "std")]
+    #[error("i/o error: {kind}")]
+    Io { kind: std::io::ErrorKind },
+    #[error("secret has to be non-negative")]
+    NegativeSecret,
+    #[error("share from dealer {dealer} does not match that dealer's commitments")]
+    DealerVerificationFailed { dealer: usize },
+    #[error("shares disagree, at least one of them is invalid")]
+    InconsistentShares,
+    #[error("binary field degree {degree} is not a multiple of 8 between 8 and 128")]
+    UnsupportedFieldDegree { degree: u32 },
+    #[error("field polynomial is not an irreducible polynomial of the given degree")]
+    ReducibleFieldPolynomial,
+}
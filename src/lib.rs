@@ -0,0 +1,12 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod algorithms;
+pub mod error;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use algorithms::share_bundle::combine;
+#[cfg(feature = "std")]
+pub use algorithms::share_bundle::split;
@@ -1,6 +1,7 @@
-use algorithms::{feldman_vss::FeldmanVSS, shamir_secret_sharing::ShamirSecretSharing};
 use num_bigint::BigInt;
-pub mod algorithms;
+use secret_sharing::algorithms::{
+    feldman_vss::FeldmanVSS, shamir_secret_sharing::ShamirSecretSharing,
+};
 fn main() {
     let threshold = 2;
     let secret = BigInt::from(786);
@@ -0,0 +1,31 @@
+use num_bigint::BigInt;
+use wasm_bindgen::prelude::*;
+
+use crate::algorithms::encoding::{bigint_from_hex, bigint_to_hex, share_from_hex, share_to_hex};
+use crate::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+use crate::error::SecretSharingError;
+
+// split a hex secret over the default prime, returns the shares as "index:hexvalue" strings
+#[wasm_bindgen]
+pub fn split(threshold: usize, shares: usize, secret_hex: &str) -> Result<Vec<String>, JsError> {
+    let secret = bigint_from_hex(secret_hex)?;
+    let mut shamir = ShamirSecretSharing::new(threshold, shares, None)?;
+    Ok(shamir
+        .generate_shares_tuples(secret)?
+        .iter()
+        .map(share_to_hex)
+        .collect())
+}
+
+// combine a json array of "index:hexvalue" strings, every share is interpolated
+#[wasm_bindgen]
+pub fn combine(shares_json: &str) -> Result<String, JsError> {
+    let strings: Vec<String> = serde_json::from_str(shares_json)
+        .map_err(|_| SecretSharingError::InvalidEncoding("shares are not a json string array"))?;
+    let shares = strings
+        .iter()
+        .map(|s| share_from_hex(s))
+        .collect::<Result<Vec<(usize, BigInt)>, _>>()?;
+    let shamir = ShamirSecretSharing::new(shares.len(), shares.len(), None)?;
+    Ok(bigint_to_hex(&shamir.reconstruct_tuples(&shares)?))
+}
@@ -0,0 +1,62 @@
+use assert_cmd::Command;
+
+fn sss() -> Command {
+    Command::cargo_bin("sss").unwrap()
+}
+
+#[test]
+fn split_combine_round_trip_test() {
+    let output = sss()
+        .args([
+            "split",
+            "--threshold",
+            "3",
+            "--shares",
+            "5",
+            "--secret",
+            "1234",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let shares: Vec<String> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    assert_eq!(shares.len(), 5, "Split should print one share per line");
+
+    let mut combine = sss();
+    combine.arg("combine");
+    for share in &shares[1..4] {
+        combine.args(["--share", share]);
+    }
+    combine.assert().success().stdout("1234\n");
+}
+
+#[test]
+fn combine_rejects_malformed_share_test() {
+    sss()
+        .args(["combine", "--share", "not-a-share"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn split_reports_invalid_parameters_test() {
+    sss()
+        .args([
+            "split",
+            "--threshold",
+            "6",
+            "--shares",
+            "5",
+            "--secret",
+            "1",
+        ])
+        .assert()
+        .failure()
+        .stderr("error: threshold 6 has to be at most total shares 5\n");
+}
@@ -0,0 +1,73 @@
+use num_bigint::{BigInt, Sign};
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+use secret_sharing::algorithms::feldman_vss::FeldmanVSS;
+use secret_sharing::algorithms::primes::{p128, p256, p32};
+use secret_sharing::algorithms::shamir_secret_sharing::ShamirSecretSharing;
+use secret_sharing::algorithms::share::Share;
+
+// a small prime, where collisions would show up quickly, next to the presets
+fn primes() -> Vec<BigInt> {
+    vec![BigInt::from(7919), p32(), p128(), p256()]
+}
+
+// (threshold, total_shares, prime, secret below the prime)
+fn dealing() -> impl Strategy<Value = (usize, usize, BigInt, BigInt)> {
+    (
+        2usize..=10,
+        0usize..=4,
+        select(primes()),
+        prop::collection::vec(any::<u8>(), 0..40),
+    )
+        .prop_map(|(threshold, extra, prime, bytes)| {
+            let secret = BigInt::from_bytes_be(Sign::Plus, &bytes) % &prime;
+            (threshold, threshold + extra, prime, secret)
+        })
+}
+
+proptest! {
+    #[test]
+    fn any_threshold_subset_reconstructs(
+        ((threshold, total_shares, prime, secret), picks) in dealing().prop_flat_map(|d| {
+            let (threshold, total_shares, _, _) = d.clone();
+            (Just(d), subsequence((0..total_shares).collect::<Vec<usize>>(), threshold))
+        })
+    ) {
+        let mut shamir = ShamirSecretSharing::new(threshold, total_shares, Some(prime)).unwrap();
+        let shares = shamir.generate_shares(secret.clone()).unwrap();
+        let subset: Vec<Share> = picks.iter().map(|i| shares[*i].clone()).collect();
+        prop_assert_eq!(shamir.reconstruct(&subset).unwrap(), secret);
+    }
+
+    #[test]
+    fn below_threshold_reveals_nothing_deterministic(
+        (threshold, total_shares, prime, secret) in dealing()
+    ) {
+        // three independent dealings of the same secret, interpolated through the same
+        // threshold - 1 indices. all three agreeing would mean the subset pins down f(0)
+        let guesses: Vec<BigInt> = (0..3)
+            .map(|_| {
+                let mut shamir =
+                    ShamirSecretSharing::new(threshold, total_shares, Some(prime.clone())).unwrap();
+                let shares = shamir.generate_shares_tuples(secret.clone()).unwrap();
+                shamir
+                    .reconstruct_with_threshold(&shares[..threshold - 1], threshold - 1)
+                    .unwrap()
+            })
+            .collect();
+        prop_assert!(
+            guesses.iter().any(|guess| *guess != guesses[0]),
+            "threshold - 1 shares should not determine f(0)"
+        );
+    }
+
+    #[test]
+    fn commitments_validate_honest_shares(
+        (threshold, total_shares, prime, secret) in dealing()
+    ) {
+        let mut vss = FeldmanVSS::new(threshold, total_shares, Some(prime)).unwrap();
+        let response = vss.generate_shares(secret).unwrap();
+        prop_assert_eq!(response.committments.len(), threshold);
+        prop_assert!(vss.validate_all(&response.shares).is_empty());
+    }
+}